@@ -1,96 +1,124 @@
 /*!
 The math behind the Glicko2 algorithm
 */
-use crate::glicko2::{constants::EPSILON, game::Outcome, rating::Rating};
+use crate::glicko2::{
+    constants::{DRAW, RATIO},
+    error::GlickoError,
+    game::{Outcome, Side},
+    rating::{validate_non_negative, Glicko2Rating, ProvisionalRating, Rating},
+    solver::{Criterion, SolverReport},
+    tuning::Algorithm,
+};
 
-/// This function reduces the impact of games as a function of an opponent's rating deviation.
-pub(crate) fn reduce_impact(rating: &Rating, other_rating: &Rating) -> f64 {
-    // Must be called for scaled ratings
-    if !rating.is_scaled || !other_rating.is_scaled {
-        panic!("Unscaled ratings passed to reduce impact!");
-    }
-    let phi = rating.phi.powi(2) + other_rating.phi.powi(2);
-    let phi_sqrt = phi.sqrt();
+/// The paper's g(phi), which shrinks the weight of a game as rating deviation grows.
+fn g(phi: f64) -> f64 {
     let pi_2 = std::f64::consts::PI.powi(2);
-    let denominator = 1.0 + (3.0 * phi_sqrt.powi(2)) / pi_2;
+    let denominator = 1.0 + (3.0 * phi.powi(2)) / pi_2;
     1.0 / denominator.sqrt()
 }
 
-/// The expected outcome of a game given two sets of ratings.
-pub(crate) fn expect_score(rating: &Rating, other_rating: &Rating, impact: f64) -> f64 {
-    if !rating.is_scaled || !other_rating.is_scaled {
-        panic!("Unscaled ratings passed to expect score!");
+/// This function reduces the impact of games as a function of both teams' rating deviations.
+/// This is used for predictions, where the uncertainty of both sides matters.
+pub(crate) fn reduce_impact(rating: &Glicko2Rating, other_rating: &Glicko2Rating) -> f64 {
+    let phi = rating.phi.powi(2) + other_rating.phi.powi(2);
+    g(phi.sqrt())
+}
+
+/// The impact used for predictions, which ignores rating deviation under Elo.
+pub(crate) fn prediction_impact(rating: &Glicko2Rating, other_rating: &Glicko2Rating) -> f64 {
+    match rating.tuning.algorithm {
+        Algorithm::Elo { .. } => 1.0,
+        _ => reduce_impact(rating, other_rating),
     }
-    let new_impact = -impact * (rating.mu - other_rating.mu);
-    1.0 / (1.0 + new_impact.exp())
 }
 
-/// Determine the new value for volatility given a set of ratings.
-fn determine_sigma(rating: &Rating, difference: &f64, variance: &f64) -> f64 {
-    let phi = rating.phi;
-    let diff_squared = difference.powi(2);
-    // 1. Let a = ln(sigma^2)
-    let alpha = rating.sigma.powi(2).ln();
-
-    // Define optimality criterion as a closure so we do not pass references for the above
-    let optimality_criterion = |x: f64| -> f64 {
-        let tmp = phi.powi(2) + variance + x.exp();
-        let tmp_2 = 2.0 * tmp.powi(2);
-        let a = x.exp() * (diff_squared - tmp) / tmp_2;
-        let b = (x - alpha) / rating.tuning.tau.powi(2);
-        a - b
-    };
+/// This function reduces the impact of a game as a function of the opponent's rating deviation.
+/// This is used for rating updates, per Step 3 of the paper.
+pub(crate) fn reduce_opponent_impact(other_rating: &Glicko2Rating) -> f64 {
+    g(other_rating.phi)
+}
 
-    // 2. Set the initial value for the iterative algorithm
-    let mut a = alpha;
-    let mut b = if diff_squared > (phi.powi(2) + variance) {
-        (diff_squared - phi.powi(2) - variance).ln()
-    } else {
-        let mut k = 1.0;
-        while optimality_criterion(alpha - k * rating.tuning.tau) < 0.0 {
-            k += 1.0;
-        }
-        alpha - k * rating.tuning.tau
-    };
+/// The expected outcome of a game given two sets of ratings, where `advantage` is added to the
+/// first team's rating on the Glicko2 scale.
+pub(crate) fn expect_score(
+    rating: &Glicko2Rating,
+    other_rating: &Glicko2Rating,
+    impact: f64,
+    advantage: f64,
+) -> f64 {
+    let new_impact = -impact * (rating.mu - other_rating.mu + advantage);
+    1.0 / (1.0 + libm::exp(new_impact))
+}
 
-    // 3. Let fA = optimality_criterion(A) and f(B) = optimality_criterion(B)
-    let mut f_a = optimality_criterion(a);
-    let mut f_b = optimality_criterion(b);
-
-    // 4. While |B-A| > e, carry out the following steps:
-    // (a) Let C = A + (A - B)fA / (fB-fA), and let fC = f(C).
-    // (b) If fCfB < 0, then set A <- B and fA <- fB; otherwise, just set
-    //     fA <- fA/2.
-    // (c) Set B <- C and fB <- fC.
-    // (d) Stop if |B-A| <= e. Repeat the above three steps otherwise.
-    while (b - a).abs() > EPSILON {
-        let c = a + (a - b) * f_a / (f_b - f_a);
-        let f_c = optimality_criterion(c);
-        if f_c * f_b < 0.0 {
-            a = b;
-            f_a = f_b;
-        } else {
-            f_a /= 2.0;
+/// The rating deviation at the start of a period, Phi*, after a period of time has passed.
+pub(crate) fn pre_period_phi(rating: &Glicko2Rating, sigma: f64) -> f64 {
+    match rating.tuning.algorithm {
+        Algorithm::Glicko2 => (rating.phi.powi(2) + sigma.powi(2)).sqrt(),
+        // Glicko-1 grows the deviation by c, but never past the initial deviation
+        Algorithm::Glicko1 { c } => {
+            let phi = (rating.phi.powi(2) + (c / RATIO).powi(2)).sqrt();
+            phi.min(rating.tuning.phi / RATIO)
         }
-        b = c;
-        f_b = f_c;
+        Algorithm::Elo { .. } => rating.phi,
     }
+}
 
-    // 5. Once |B-A| <= e, set s' <- e^(A/2)
-    1.0f64.exp().powf(a / 2.0)
+/// The rating deviation after `periods` periods without games, applied in one step and never
+/// past the initial deviation.
+pub(crate) fn decayed_phi(rating: &Glicko2Rating, periods: f64) -> f64 {
+    let growth = match rating.tuning.algorithm {
+        Algorithm::Glicko2 => rating.sigma,
+        Algorithm::Glicko1 { c } => c / RATIO,
+        Algorithm::Elo { .. } => return rating.phi,
+    };
+    let phi = (rating.phi.powi(2) + periods * growth.powi(2)).sqrt();
+    // A rating that already started past the cap is never made more certain by time passing
+    phi.min(rating.phi.max(rating.tuning.phi / RATIO))
+}
+
+/// Determine the new value for volatility given a set of ratings.
+fn determine_sigma(
+    rating: &Glicko2Rating,
+    difference: &f64,
+    variance: &f64,
+) -> Result<(f64, SolverReport), GlickoError> {
+    // 1. Let a = ln(sigma^2), and define the optimality criterion f(x)
+    let criterion = Criterion {
+        alpha: libm::log(rating.sigma.powi(2)),
+        spread: rating.phi.powi(2) + variance,
+        diff_squared: difference.powi(2),
+        tau: rating.tuning.tau,
+    };
+
+    // 2-4. Iterate with the configured method until the root is found
+    let (x, mut report) = criterion.solve(&rating.tuning.solver);
+
+    // 5. Once the root is found, set s' <- e^(A/2)
+    let sigma = libm::exp(x / 2.0);
+    report.converged &= sigma.is_finite() && sigma > 0.0;
+    if report.converged {
+        Ok((sigma, report))
+    } else {
+        Err(GlickoError::NonConvergence(report))
+    }
 }
 
 /// Given a team and a set of outcomes in a period, update the team's ratings.
 /// Because this modifies the rating of the team in-place, you may want to pass a copy
 /// if you wish to preserve old ratings.
 ///
+/// # Panics
+///
+/// Panics on any error that [`try_rate`] would return, such as an [`Outcome::Score`]
+/// outside `[0, 1]`.
+///
 /// # Example
 ///
 /// ```
 /// use glicko_2::{Rating, Tuning, game::Outcome};
 ///
 /// let tuning = Tuning::default();
-/// 
+///
 /// let mut team_to_update = Rating::new(&tuning);
 /// let mut opponent_1 = Rating::new(&tuning);
 /// let mut opponent_2 = Rating::new(&tuning);
@@ -105,12 +133,385 @@ fn determine_sigma(rating: &Rating, difference: &f64, variance: &f64) -> f64 {
 /// );
 /// ```
 pub fn rate(rating: &mut Rating, outcomes: Vec<(Outcome, &mut Rating)>) {
-    // Outcome is a list of outcomes for a set of games between two teams, i.e.
-    //   a vector tuples like [(WIN, rating2), ...]
+    if let Err(error) = try_rate(rating, outcomes) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate`]. The rating is left untouched if an error is returned.
+/// On success, returns a report describing how the volatility solver behaved.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, GlickoError, game::Outcome};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut team_to_update = Rating::new(&tuning);
+/// let mut opponent = Rating::new(&tuning);
+///
+/// assert_eq!(
+///     glicko_2::algorithm::try_rate(&mut team_to_update, vec![]),
+///     Err(GlickoError::NoOutcomes)
+/// );
+/// assert!(glicko_2::algorithm::try_rate(&mut team_to_update, vec![(Outcome::Win, &mut opponent)]).is_ok());
+/// ```
+pub fn try_rate(
+    rating: &mut Rating,
+    outcomes: Vec<(Outcome, &mut Rating)>,
+) -> Result<SolverReport, GlickoError> {
+    try_rate_weighted(
+        rating,
+        outcomes
+            .into_iter()
+            .map(|(outcome, other_rating)| (outcome, 1.0, other_rating))
+            .collect(),
+    )
+}
+
+/// Given a team and a set of weighted outcomes in a period, update the team's ratings.
+/// A game's weight scales its contribution to the update, so a game with weight `2.0`
+/// counts as much as two identical games with weight `1.0`.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_rate_weighted`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::Outcome};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut team_to_update = Rating::new(&tuning);
+/// let mut cup_final_opponent = Rating::new(&tuning);
+/// let mut scrim_opponent = Rating::new(&tuning);
+///
+/// glicko_2::algorithm::rate_weighted(
+///     &mut team_to_update,
+///     vec![(Outcome::Score(0.625), 3.0, &mut cup_final_opponent),
+///          (Outcome::Loss, 0.5, &mut scrim_opponent),
+///      ]
+/// );
+/// ```
+pub fn rate_weighted(rating: &mut Rating, outcomes: Vec<(Outcome, f64, &mut Rating)>) {
+    if let Err(error) = try_rate_weighted(rating, outcomes) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate_weighted`]. The rating is left untouched if an error is returned.
+/// On success, returns a report describing how the volatility solver behaved.
+pub fn try_rate_weighted(
+    rating: &mut Rating,
+    outcomes: Vec<(Outcome, f64, &mut Rating)>,
+) -> Result<SolverReport, GlickoError> {
+    try_rate_games(
+        rating,
+        outcomes
+            .into_iter()
+            .map(|(outcome, weight, other_rating)| Game {
+                weight,
+                ..Game::new(outcome, other_rating)
+            })
+            .collect(),
+    )
+}
+
+/// Given a team and a set of outcomes in a period, update the team's ratings, applying the
+/// team's [`Tuning::advantage`](crate::Tuning) to whichever side was at home or moved first.
+/// Each [`Side`] is from the perspective of the team being rated.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_rate_sided`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::{Outcome, Side}};
+///
+/// let tuning = Tuning::default().with_advantage(50.0);
+///
+/// let mut home_winner = Rating::new(&tuning);
+/// let mut away_winner = Rating::new(&tuning);
+/// let mut opponent = Rating::new(&tuning);
+///
+/// glicko_2::algorithm::rate_sided(&mut home_winner, vec![(Outcome::Win, Side::Home, &mut opponent)]);
+/// glicko_2::algorithm::rate_sided(&mut away_winner, vec![(Outcome::Win, Side::Away, &mut opponent)]);
+///
+/// // Winning away is the bigger achievement
+/// assert!(away_winner.mu > home_winner.mu);
+/// ```
+pub fn rate_sided(rating: &mut Rating, outcomes: Vec<(Outcome, Side, &mut Rating)>) {
+    if let Err(error) = try_rate_sided(rating, outcomes) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate_sided`]. The rating is left untouched if an error is returned.
+/// On success, returns a report describing how the volatility solver behaved.
+pub fn try_rate_sided(
+    rating: &mut Rating,
+    outcomes: Vec<(Outcome, Side, &mut Rating)>,
+) -> Result<SolverReport, GlickoError> {
+    try_rate_games(
+        rating,
+        outcomes
+            .into_iter()
+            .map(|(outcome, side, other_rating)| Game {
+                side,
+                ..Game::new(outcome, other_rating)
+            })
+            .collect(),
+    )
+}
+
+/// Given a team and a set of outcomes in a period along with the absolute score differential
+/// of each game, update the team's ratings. Decisive wins and losses scale the team's
+/// estimated improvement through the team's [`Tuning::margin`](crate::Tuning) configuration;
+/// without one, margins are ignored. Draws are never scaled.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_rate_margin`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::Outcome, margin::MarginOfVictory};
+///
+/// let tuning = Tuning::default().with_margin(MarginOfVictory::default());
+///
+/// let mut stomp = Rating::new(&tuning);
+/// let mut squeaker = Rating::new(&tuning);
+/// let mut opponent = Rating::new(&tuning);
+///
+/// glicko_2::algorithm::rate_margin(&mut stomp, vec![(Outcome::Win, 10.0, &mut opponent)]);
+/// glicko_2::algorithm::rate_margin(&mut squeaker, vec![(Outcome::Win, 1.0, &mut opponent)]);
+///
+/// assert!(stomp.mu > squeaker.mu);
+/// ```
+pub fn rate_margin(rating: &mut Rating, outcomes: Vec<(Outcome, f64, &mut Rating)>) {
+    if let Err(error) = try_rate_margin(rating, outcomes) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate_margin`]. The rating is left untouched if an error is returned.
+/// On success, returns a report describing how the volatility solver behaved.
+pub fn try_rate_margin(
+    rating: &mut Rating,
+    outcomes: Vec<(Outcome, f64, &mut Rating)>,
+) -> Result<SolverReport, GlickoError> {
+    try_rate_games(
+        rating,
+        outcomes
+            .into_iter()
+            .map(|(outcome, margin, other_rating)| Game {
+                margin: Some(margin),
+                ..Game::new(outcome, other_rating)
+            })
+            .collect(),
+    )
+}
+
+/// Estimate a team's rating partway through a rating period from its pre-period rating and
+/// the games played so far, without changing anything. With no games yet, the estimate is
+/// the pre-period rating.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_provisional`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::Outcome};
+///
+/// let tuning = Tuning::default();
+///
+/// let team = Rating::new(&tuning);
+/// let opponent = Rating::new(&tuning);
+///
+/// let live = glicko_2::algorithm::provisional(&team, vec![(Outcome::Win, &opponent)]);
+/// assert!(live.mu() > team.mu);
+/// ```
+pub fn provisional(rating: &Rating, outcomes: Vec<(Outcome, &Rating)>) -> ProvisionalRating {
+    match try_provisional(rating, outcomes) {
+        Ok(provisional) => provisional,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`provisional`].
+pub fn try_provisional(
+    rating: &Rating,
+    outcomes: Vec<(Outcome, &Rating)>,
+) -> Result<ProvisionalRating, GlickoError> {
+    let mut estimate = *rating;
+    if outcomes.is_empty() {
+        estimate.validate()?;
+    } else {
+        let mut opponents: Vec<Rating> = outcomes.iter().map(|(_, other)| **other).collect();
+        try_rate_games(
+            &mut estimate,
+            outcomes
+                .iter()
+                .zip(opponents.iter_mut())
+                .map(|((outcome, _), other_rating)| Game::new(*outcome, other_rating))
+                .collect(),
+        )?;
+    }
+    Ok(ProvisionalRating::new(&estimate))
+}
+
+/// A single game from the perspective of the team being rated.
+pub(crate) struct Game<'a> {
+    pub(crate) outcome: Outcome,
+    pub(crate) weight: f64,
+    pub(crate) side: Side,
+    /// The absolute score differential, if the game should be scaled by its margin
+    pub(crate) margin: Option<f64>,
+    pub(crate) opponent: &'a mut Rating,
+}
+
+impl<'a> Game<'a> {
+    /// A regular game on neutral ground, with no weight or margin applied.
+    pub(crate) fn new(outcome: Outcome, opponent: &'a mut Rating) -> Self {
+        Self {
+            outcome,
+            weight: 1.0,
+            side: Side::Neutral,
+            margin: None,
+            opponent,
+        }
+    }
+}
+
+/// What a single game contributed to a rating update. Values are on the Glicko2 scale unless
+/// noted otherwise.
+///
+/// - `impact`: g(φ) of the opponent, how much the opponent's uncertainty shrinks the game
+/// - `expected_score`: The score the team was expected to get
+/// - `variance_contribution`: The game's share of 1 / v, how much it taught us about the team
+/// - `difference_contribution`: The game's share of Δ, the estimated improvement
+/// - `mu_change`: The game's share of the change in rating, in rating points
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GameImpact {
+    pub impact: f64,
+    pub expected_score: f64,
+    pub variance_contribution: f64,
+    pub difference_contribution: f64,
+    pub mu_change: f64,
+}
+
+/// A step-by-step breakdown of a rating update, as returned by [`explain_rate`].
+///
+/// - `games`: What each game contributed, in the order they were passed
+/// - `variance`: v, the estimated variance of the rating based only on game outcomes
+/// - `difference`: Δ, the estimated improvement in rating based only on game outcomes
+/// - `sigma`: σ', the new volatility
+/// - `phi_star`: φ*, the rating deviation after time passed but before the games, in rating points
+/// - `rating`: The rating after the update, identical to what [`rate`] produces
+/// - `report`: How the volatility solver behaved
+#[derive(Debug, Clone)]
+pub struct RateExplanation {
+    pub games: Vec<GameImpact>,
+    pub variance: f64,
+    pub difference: f64,
+    pub sigma: f64,
+    pub phi_star: f64,
+    pub rating: Rating,
+    pub report: SolverReport,
+}
+
+/// Explain how a set of outcomes in a period would update a team's rating, game by game,
+/// without changing anything. The per-game `mu_change` values add up to the total change.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_explain_rate`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::Outcome};
+///
+/// let tuning = Tuning::default();
+///
+/// let team = Rating::new(&tuning);
+/// let mut favorite = Rating::new(&tuning);
+/// favorite.mu = 1800.0;
+/// let mut underdog = Rating::new(&tuning);
+/// underdog.mu = 1200.0;
+///
+/// let explanation = glicko_2::algorithm::explain_rate(
+///     &team,
+///     vec![(Outcome::Win, &favorite), (Outcome::Win, &underdog)],
+/// );
+///
+/// // Beating the favorite was worth more than beating the underdog
+/// assert!(explanation.games[0].mu_change > explanation.games[1].mu_change);
+/// ```
+pub fn explain_rate(rating: &Rating, outcomes: Vec<(Outcome, &Rating)>) -> RateExplanation {
+    match try_explain_rate(rating, outcomes) {
+        Ok(explanation) => explanation,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`explain_rate`].
+pub fn try_explain_rate(
+    rating: &Rating,
+    outcomes: Vec<(Outcome, &Rating)>,
+) -> Result<RateExplanation, GlickoError> {
+    let mut opponents: Vec<Rating> = outcomes.iter().map(|(_, other)| **other).collect();
+    explain_games(
+        rating,
+        outcomes
+            .iter()
+            .zip(opponents.iter_mut())
+            .map(|((outcome, _), other_rating)| Game::new(*outcome, other_rating))
+            .collect(),
+    )
+}
+
+/// Shared implementation of the rating update for every kind of game.
+pub(crate) fn try_rate_games(
+    rating: &mut Rating,
+    games: Vec<Game>,
+) -> Result<SolverReport, GlickoError> {
+    let explanation = explain_games(rating, games)?;
+    *rating = explanation.rating;
+    Ok(explanation.report)
+}
+
+/// Compute a rating update along with every intermediate value, without writing anything.
+fn explain_games(rating: &Rating, games: Vec<Game>) -> Result<RateExplanation, GlickoError> {
+    if games.is_empty() {
+        return Err(GlickoError::NoOutcomes);
+    }
+    rating.validate()?;
+    for game in &games {
+        if !game.outcome.is_valid() {
+            return Err(GlickoError::InvalidOutcome(game.outcome.val()));
+        }
+        if !(game.weight.is_finite() && game.weight > 0.0) {
+            return Err(GlickoError::InvalidWeight(game.weight));
+        }
+        if let Some(margin) = game.margin {
+            validate_non_negative("margin", margin)?;
+        }
+        game.opponent.validate()?;
+    }
 
     // Step 2. For each player, convert the rating and rating deviation onto the
-    //         Glicko-2 scale.
-    rating.scale_down();
+    //         Glicko-2 scale. Work on a copy so errors leave the rating untouched.
+    let mut scaled = rating.to_glicko2();
 
     // Step 3. Compute the quantity v. This is the estimated variance of the
     //         team's/player's rating based only on game outcomes.
@@ -119,35 +520,100 @@ pub fn rate(rating: &mut Rating, outcomes: Vec<(Outcome, &mut Rating)>) {
     //         rating based only on game outcomes.
     let mut variance_inv = 0.0;
     let mut difference = 0.0;
+    // Each game's (impact, expected score, share of 1 / v, share of the improvement)
+    let mut contributions = Vec::with_capacity(games.len());
 
-    for (score, other_rating) in outcomes {
-        other_rating.scale_down();
-        let impact = reduce_impact(rating, other_rating);
-        let expected = expect_score(rating, other_rating, impact);
+    for game in games {
+        // Only a converted copy is used, so the opponent never drifts through a round trip
+        let other_rating = game.opponent.to_glicko2();
+        let score = game.outcome.val();
+        let impact = match scaled.tuning.algorithm {
+            Algorithm::Elo { .. } => 1.0,
+            _ => reduce_opponent_impact(&other_rating),
+        };
+        let advantage = game.side.advantage(&scaled.tuning);
+        let expected = expect_score(&scaled, &other_rating, impact, advantage);
         let expected_inv = expected * (1.0 - expected);
-        variance_inv += impact.powi(2) * expected_inv;
-        difference += impact * (score.val() - expected);
-        other_rating.scale_up();
+        // Decisive margins only scale the improvement, and shrink when the winner was favored
+        let multiplier = match (game.margin, scaled.tuning.margin) {
+            (Some(margin), Some(config)) if score != DRAW => {
+                let lead = scaled.mu - other_rating.mu + advantage;
+                let favored_by = if score > DRAW { lead } else { -lead };
+                config.multiplier(margin, favored_by)
+            }
+            _ => 1.0,
+        };
+        let variance_term = game.weight * impact.powi(2) * expected_inv;
+        let difference_term = game.weight * multiplier * impact * (score - expected);
+        variance_inv += variance_term;
+        difference += difference_term;
+        contributions.push((impact, expected, variance_term, difference_term));
     }
 
-    difference /= variance_inv.max(0.0001);
+    let improvement = difference;
+    let difference_scale = variance_inv.max(0.0001);
+    difference /= difference_scale;
     let variance = 1.0 / variance_inv;
 
     // Step 5. Determine the new value, Sigma', or the sigma. This
-    //         computation requires iteration.
-    let sigma = determine_sigma(rating, &difference, &variance);
+    //         computation requires iteration. Glicko-1 and Elo have no volatility to update.
+    let (sigma, report) = match scaled.tuning.algorithm {
+        Algorithm::Glicko2 => determine_sigma(&scaled, &difference, &variance)?,
+        Algorithm::Glicko1 { .. } | Algorithm::Elo { .. } => (
+            scaled.sigma,
+            SolverReport {
+                iterations: 0,
+                converged: true,
+            },
+        ),
+    };
 
     // Step 6. Update the rating deviation to the new pre-rating period
     //         value, Phi*.
-    let phi_star = (rating.phi.powi(2) + sigma.powi(2)).sqrt();
+    let phi_star = pre_period_phi(&scaled, sigma);
 
     // Step 7. Update the rating and rating deviation to the new values, Mu' and Phi'.
-    let phi = 1.0 / ((1.0 / phi_star).powi(2) + (1.0 / variance)).sqrt();
-    let mu = (rating.mu + phi).powi(2) * (difference / variance);
+    //         Elo instead moves the rating by K points per point scored above expectation.
+    let (phi, mu) = match scaled.tuning.algorithm {
+        Algorithm::Elo { k } => (scaled.phi, scaled.mu + k.k(rating.mu) / RATIO * improvement),
+        _ => {
+            let phi = 1.0 / ((1.0 / phi_star).powi(2) + (1.0 / variance)).sqrt();
+            (phi, scaled.mu + phi.powi(2) * (difference / variance))
+        }
+    };
+    let games = contributions
+        .into_iter()
+        .map(
+            |(impact, expected_score, variance_contribution, difference_term)| {
+                let difference_contribution = difference_term / difference_scale;
+                let mu_change = match scaled.tuning.algorithm {
+                    Algorithm::Elo { k } => k.k(rating.mu) * difference_term,
+                    _ => phi.powi(2) * (difference_contribution / variance) * RATIO,
+                };
+                GameImpact {
+                    impact,
+                    expected_score,
+                    variance_contribution,
+                    difference_contribution,
+                    mu_change,
+                }
+            },
+        )
+        .collect();
 
     // Step 8. Convert rating and rating deviation back to original scale.
-    rating.mu = mu;
-    rating.phi = phi;
-    rating.sigma = sigma;
-    rating.scale_up(); // Since this is a reference, we can just scale it back
+    scaled.mu = mu;
+    scaled.phi = phi;
+    scaled.sigma = sigma;
+    let updated = scaled.to_display();
+    updated.validate()?;
+    Ok(RateExplanation {
+        games,
+        variance,
+        difference,
+        sigma,
+        phi_star: phi_star * RATIO,
+        rating: updated,
+        report,
+    })
 }
@@ -17,6 +17,27 @@ pub const TAU: f64 = 1.3;
 /// Default convergence tolerance
 pub const EPSILON: f64 = 0.0000001;
 
+/// Default maximum number of iterations for the volatility solver
+pub const MAX_ITERATIONS: usize = 10_000;
+
+/// Default draw parameter for three-way predictions, where zero means draws never happen
+pub const DRAW_PARAMETER: f64 = 0.0;
+
+/// Default advantage in rating points for the home or first-move side, where zero means none
+pub const ADVANTAGE: f64 = 0.0;
+
+/// Default margin of victory autocorrelation correction per rating point the winner was favored by
+pub const MARGIN_AUTOCORRELATION: f64 = 0.001 / 2.2;
+
+/// Default smallest margin of victory multiplier
+pub const MARGIN_MIN_MULTIPLIER: f64 = 0.5;
+
+/// Default largest margin of victory multiplier
+pub const MARGIN_MAX_MULTIPLIER: f64 = 3.0;
+
+/// Default number of rating deviations subtracted from a rating for its conservative score
+pub const CONSERVATIVE_K: f64 = 2.0;
+
 /// Glicko-2 scale ratio
 pub const RATIO: f64 = 173.7178;
 
@@ -0,0 +1,112 @@
+/*!
+Elo interoperability: conversions between ratings and Elo, and K-factor schedules for
+[`Algorithm::Elo`](crate::Algorithm::Elo)
+*/
+use crate::glicko2::{
+    error::GlickoError,
+    rating::{validate_positive, Rating},
+    tuning::Tuning,
+};
+
+/// How many rating points a full point of surprise moves an Elo rating.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KFactor {
+    /// The same K for every rating
+    Constant(f64),
+    /// `below` for ratings under `threshold` and `above` from then on, such as FIDE's
+    /// `20` and `10` either side of `2400`
+    Tiered {
+        below: f64,
+        threshold: f64,
+        above: f64,
+    },
+}
+
+impl KFactor {
+    /// The K-factor for a team with the given rating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::elo::KFactor;
+    ///
+    /// let fide = KFactor::Tiered { below: 20.0, threshold: 2400.0, above: 10.0 };
+    /// assert_eq!(fide.k(2100.0), 20.0);
+    /// assert_eq!(fide.k(2450.0), 10.0);
+    /// ```
+    pub fn k(&self, rating: f64) -> f64 {
+        match self {
+            KFactor::Constant(k) => *k,
+            KFactor::Tiered {
+                below,
+                threshold,
+                above,
+            } => {
+                if rating < *threshold {
+                    *below
+                } else {
+                    *above
+                }
+            }
+        }
+    }
+
+    /// Checks that every K is positive and any threshold is finite.
+    pub(crate) fn validate(&self) -> Result<(), GlickoError> {
+        match self {
+            KFactor::Constant(k) => validate_positive("tuning.algorithm.k", *k),
+            KFactor::Tiered {
+                below,
+                threshold,
+                above,
+            } => {
+                validate_positive("tuning.algorithm.k.below", *below)?;
+                validate_positive("tuning.algorithm.k.above", *above)?;
+                if !threshold.is_finite() {
+                    return Err(GlickoError::NonFinite("tuning.algorithm.k.threshold"));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The Elo rating equivalent to a rating. The display scale uses the same `400 / ln(10)`
+/// spread as Elo, so the rating itself is the Elo equivalent and the deviation is dropped.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, elo};
+///
+/// let tuning = Tuning::default();
+/// let rating = Rating::new(&tuning);
+///
+/// assert_eq!(elo::to_elo(&rating), 1500.0);
+/// ```
+pub fn to_elo(rating: &Rating) -> f64 {
+    rating.mu
+}
+
+/// Seed a rating from an Elo rating, with a chosen rating deviation to reflect how much the
+/// Elo rating is trusted. Volatility starts from the tuning's default.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Tuning, elo};
+///
+/// let tuning = Tuning::default();
+/// let rating = elo::from_elo(2100.0, 80.0, &tuning);
+///
+/// assert_eq!(rating.mu, 2100.0);
+/// assert_eq!(rating.phi, 80.0);
+/// ```
+pub fn from_elo(elo: f64, phi: f64, tuning: &Tuning) -> Rating {
+    Rating {
+        mu: elo,
+        phi,
+        ..Rating::new(tuning)
+    }
+}
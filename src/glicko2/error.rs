@@ -0,0 +1,79 @@
+/*!
+Errors returned by the fallible variants of the rating functions
+*/
+use std::{error::Error, fmt};
+
+use crate::glicko2::solver::SolverReport;
+
+/// Reasons a rating calculation can fail instead of producing a new rating.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlickoError {
+    /// A field is `NaN` or infinite
+    NonFinite(&'static str),
+    /// A field that must be strictly positive is zero or negative
+    NonPositive(&'static str),
+    /// A field that must not be negative is less than zero
+    Negative(&'static str),
+    /// A lower bound is greater than its upper bound
+    InvalidRange(&'static str),
+    /// An outcome's value lies outside `[0, 1]`
+    InvalidOutcome(f64),
+    /// A game weight is not a positive finite number
+    InvalidWeight(f64),
+    /// A confidence level lies outside `(0, 1)`
+    InvalidConfidence(f64),
+    /// No outcomes were provided to rate against
+    NoOutcomes,
+    /// A game references a player that has no rating
+    UnknownPlayer,
+    /// A team has no members to combine into a rating
+    EmptyTeam,
+    /// A player appears more than once in the same game
+    DuplicatePlayer,
+    /// A game in a match log was played before the game preceding it
+    OutOfOrder,
+    /// A game to invalidate does not exist in the rating history
+    UnknownGame,
+    /// The volatility solver did not converge within its iteration limit
+    NonConvergence(SolverReport),
+    /// A serialized rating was written in a format version this crate cannot read
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for GlickoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlickoError::NonFinite(field) => write!(f, "{field} is not a finite number!"),
+            GlickoError::NonPositive(field) => write!(f, "{field} must be greater than zero!"),
+            GlickoError::Negative(field) => write!(f, "{field} must not be negative!"),
+            GlickoError::InvalidRange(field) => {
+                write!(f, "{field} must not be greater than its maximum!")
+            }
+            GlickoError::InvalidOutcome(score) => {
+                write!(f, "Outcome {score} is outside of [0, 1]!")
+            }
+            GlickoError::InvalidWeight(weight) => {
+                write!(f, "Game weight {weight} is not a positive finite number!")
+            }
+            GlickoError::InvalidConfidence(confidence) => {
+                write!(f, "Confidence {confidence} is outside of (0, 1)!")
+            }
+            GlickoError::NoOutcomes => write!(f, "No outcomes passed to rate!"),
+            GlickoError::UnknownPlayer => write!(f, "Game references a player with no rating!"),
+            GlickoError::EmptyTeam => write!(f, "Team has no members!"),
+            GlickoError::DuplicatePlayer => write!(f, "Player appears more than once in a game!"),
+            GlickoError::OutOfOrder => write!(f, "Games must be replayed in timestamp order!"),
+            GlickoError::UnknownGame => write!(f, "Game does not exist in the rating history!"),
+            GlickoError::NonConvergence(report) => write!(
+                f,
+                "Volatility solver did not converge after {} iterations!",
+                report.iterations
+            ),
+            GlickoError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported rating format version {version}!")
+            }
+        }
+    }
+}
+
+impl Error for GlickoError {}
@@ -1,7 +1,13 @@
 /*!
 Provides functions to handle a single one on one game and update ratings accordingly
 */
-use crate::glicko2::{algorithm, constants, rating::Rating};
+use crate::glicko2::{
+    algorithm::{self, Game},
+    constants,
+    error::GlickoError,
+    rating::{Rating, RatingChange},
+    tuning::Tuning,
+};
 
 /// Updates ratings for two teams.
 /// If the game was a draw, pass `drawn` as `true`.
@@ -19,17 +25,153 @@ use crate::glicko2::{algorithm, constants, rating::Rating};
 /// game::compete(&mut rating_1, &mut rating_2, false);
 /// ```
 pub fn compete(winner: &mut Rating, loser: &mut Rating, drawn: bool) {
+    if let Err(error) = try_compete(winner, loser, drawn) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`compete`]. Neither rating is changed if an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, GlickoError, game};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut rating_1 = Rating::new(&tuning);
+/// let mut rating_2 = Rating::new(&tuning);
+/// rating_2.phi = f64::NAN;
+///
+/// assert_eq!(
+///     game::try_compete(&mut rating_1, &mut rating_2, false),
+///     Err(GlickoError::NonFinite("phi"))
+/// );
+/// ```
+pub fn try_compete(
+    winner: &mut Rating,
+    loser: &mut Rating,
+    drawn: bool,
+) -> Result<(), GlickoError> {
+    try_compete_sided(winner, loser, drawn, Side::Neutral)
+}
+
+/// Updates ratings for two teams, where `winner_side` says whether the first team was at home
+/// or moved first. The advantage comes from each team's [`Tuning::advantage`](crate::Tuning).
+/// If the game was a draw, pass `drawn` as `true`.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::{self, Side}};
+///
+/// let tuning = Tuning::default().with_advantage(50.0);
+///
+/// let mut rating_1 = Rating::new(&tuning);
+/// let mut rating_2 = Rating::new(&tuning);
+///
+/// // The away team won
+/// game::compete_sided(&mut rating_1, &mut rating_2, false, Side::Away);
+/// ```
+pub fn compete_sided(winner: &mut Rating, loser: &mut Rating, drawn: bool, winner_side: Side) {
+    if let Err(error) = try_compete_sided(winner, loser, drawn, winner_side) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`compete_sided`]. Neither rating is changed if an error is returned.
+pub fn try_compete_sided(
+    winner: &mut Rating,
+    loser: &mut Rating,
+    drawn: bool,
+    winner_side: Side,
+) -> Result<(), GlickoError> {
     // drawn is false if Team 1 beat Team 2
-    if drawn {
-        algorithm::rate(winner, vec![(Outcome::Draw, loser)]);
-        algorithm::rate(loser, vec![(Outcome::Draw, winner)]);
+    let outcome = if drawn { Outcome::Draw } else { Outcome::Win };
+    try_compete_games(
+        winner,
+        loser,
+        |opponent| Game {
+            side: winner_side,
+            ..Game::new(outcome, opponent)
+        },
+        |opponent| Game {
+            side: winner_side.reverse(),
+            ..Game::new(outcome.reverse(), opponent)
+        },
+    )
+}
+
+/// Updates ratings for two teams given the winner's margin of victory, such as `4.0` for a
+/// 10–6 result. A margin of zero is a draw. The margin only changes the update if the
+/// ratings' tuning opts in with [`Tuning::with_margin`](crate::Tuning::with_margin).
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game, margin::MarginOfVictory};
+///
+/// let tuning = Tuning::default().with_margin(MarginOfVictory::default());
+///
+/// let mut rating_1 = Rating::new(&tuning);
+/// let mut rating_2 = Rating::new(&tuning);
+///
+/// // Team 1 won 10–0
+/// game::compete_margin(&mut rating_1, &mut rating_2, 10.0);
+/// ```
+pub fn compete_margin(winner: &mut Rating, loser: &mut Rating, margin: f64) {
+    if let Err(error) = try_compete_margin(winner, loser, margin) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`compete_margin`]. Neither rating is changed if an error is returned.
+pub fn try_compete_margin(
+    winner: &mut Rating,
+    loser: &mut Rating,
+    margin: f64,
+) -> Result<(), GlickoError> {
+    let outcome = if margin == 0.0 {
+        Outcome::Draw
     } else {
-        algorithm::rate(winner, vec![(Outcome::Win, loser)]);
-        algorithm::rate(loser, vec![(Outcome::Loss, winner)]);
+        Outcome::Win
     };
+    try_compete_games(
+        winner,
+        loser,
+        |opponent| Game {
+            margin: Some(margin),
+            ..Game::new(outcome, opponent)
+        },
+        |opponent| Game {
+            margin: Some(margin),
+            ..Game::new(outcome.reverse(), opponent)
+        },
+    )
+}
+
+/// Rates both teams of a single game, where `winner_game` and `loser_game` build each team's
+/// view of the game against the other. Neither rating is changed if either update fails.
+fn try_compete_games(
+    winner: &mut Rating,
+    loser: &mut Rating,
+    winner_game: impl FnOnce(&mut Rating) -> Game<'_>,
+    loser_game: impl FnOnce(&mut Rating) -> Game<'_>,
+) -> Result<(), GlickoError> {
+    // Both teams are rated against the other's pre-game rating, so call order does not matter
+    let mut winner_before = *winner;
+    let mut loser_before = *loser;
+    let mut winner_after = *winner;
+    let mut loser_after = *loser;
+    algorithm::try_rate_games(&mut winner_after, vec![winner_game(&mut loser_before)])?;
+    algorithm::try_rate_games(&mut loser_after, vec![loser_game(&mut winner_before)])?;
+    *winner = winner_after;
+    *loser = loser_after;
+    Ok(())
 }
 
 /// Determines the odds the first team will beat the second team.
+/// Neither rating is modified, so this can be used on shared, read-only ratings.
 ///
 /// # Example
 ///
@@ -38,22 +180,181 @@ pub fn compete(winner: &mut Rating, loser: &mut Rating, drawn: bool) {
 ///
 /// let tuning = Tuning::default();
 ///
-/// let mut rating_1 = Rating::new(&tuning);
-/// let mut rating_2 = Rating::new(&tuning);
+/// let rating_1 = Rating::new(&tuning);
+/// let rating_2 = Rating::new(&tuning);
 ///
-/// let odds = game::odds(&mut rating_1, &mut rating_2);
+/// let odds = game::odds(&rating_1, &rating_2);
 /// ```
-pub fn odds(rating1: &mut Rating, rating2: &mut Rating) -> f64 {
-    rating1.scale_down();
-    rating2.scale_down();
-    let expected_score =
-        algorithm::expect_score(rating1, rating2, algorithm::reduce_impact(rating1, rating2));
-    rating1.scale_up();
-    rating2.scale_up();
-    expected_score
+pub fn odds(rating1: &Rating, rating2: &Rating) -> f64 {
+    match try_odds(rating1, rating2) {
+        Ok(expected_score) => expected_score,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`odds`].
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, GlickoError, game};
+///
+/// let tuning = Tuning::default();
+///
+/// let rating_1 = Rating::new(&tuning);
+/// let rating_2 = Rating::new(&tuning);
+///
+/// assert_eq!(game::try_odds(&rating_1, &rating_2), Ok(0.5));
+/// ```
+pub fn try_odds(rating1: &Rating, rating2: &Rating) -> Result<f64, GlickoError> {
+    try_odds_sided(rating1, rating2, Side::Neutral)
+}
+
+/// Determines the odds the first team will beat the second team, where `side` says whether
+/// the first team is at home or moves first. The advantage comes from the first team's
+/// [`Tuning::advantage`](crate::Tuning).
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::{self, Side}};
+///
+/// let tuning = Tuning::default().with_advantage(50.0);
+///
+/// let rating_1 = Rating::new(&tuning);
+/// let rating_2 = Rating::new(&tuning);
+///
+/// assert!(game::odds_sided(&rating_1, &rating_2, Side::Home) > 0.5);
+/// assert_eq!(game::odds_sided(&rating_1, &rating_2, Side::Neutral), 0.5);
+/// ```
+pub fn odds_sided(rating1: &Rating, rating2: &Rating, side: Side) -> f64 {
+    match try_odds_sided(rating1, rating2, side) {
+        Ok(expected_score) => expected_score,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`odds_sided`].
+pub fn try_odds_sided(rating1: &Rating, rating2: &Rating, side: Side) -> Result<f64, GlickoError> {
+    rating1.validate()?;
+    rating2.validate()?;
+    let rating1 = rating1.to_glicko2();
+    let rating2 = rating2.to_glicko2();
+    let impact = algorithm::prediction_impact(&rating1, &rating2);
+    let advantage = side.advantage(&rating1.tuning);
+    Ok(algorithm::expect_score(
+        &rating1, &rating2, impact, advantage,
+    ))
+}
+
+/// Estimates [`Tuning::advantage`](crate::Tuning), in rating points, by maximum likelihood
+/// from past games, given as the pre-game ratings of the home or first-move team and its
+/// opponent, and the outcome for the home team. Outcomes outside `[0, 1]` are ignored, as are
+/// games where either rating is invalid.
+///
+/// Returns `None` if there are no usable games, if the home team won or lost every game, or
+/// if no advantage can explain the observed score.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::{self, Outcome}};
+///
+/// let tuning = Tuning::default();
+/// let rating = Rating::new(&tuning);
+///
+/// let history = vec![
+///     (rating, rating, Outcome::Win),
+///     (rating, rating, Outcome::Win),
+///     (rating, rating, Outcome::Loss),
+/// ];
+///
+/// // Evenly matched home teams took two thirds of the points
+/// let advantage = game::fit_advantage(&history).unwrap();
+/// assert!(advantage > 0.0);
+/// ```
+pub fn fit_advantage(history: &[(Rating, Rating, Outcome)]) -> Option<f64> {
+    let mut games = Vec::with_capacity(history.len());
+    let mut score = 0.0;
+    for (home, away, outcome) in history {
+        if !outcome.is_valid() || home.validate().is_err() || away.validate().is_err() {
+            continue;
+        }
+        let home = home.to_glicko2();
+        let away = away.to_glicko2();
+        let impact = algorithm::prediction_impact(&home, &away);
+        games.push((home, away, impact));
+        score += outcome.val();
+    }
+    if games.is_empty() || score == constants::LOSS || score == games.len() as f64 {
+        return None;
+    }
+
+    // The likelihood is maximized where the expected score matches the observed score.
+    // The expected score increases with the advantage, so bracket the root and bisect.
+    let expected_score = |advantage: f64| -> f64 {
+        games
+            .iter()
+            .map(|(home, away, impact)| algorithm::expect_score(home, away, *impact, advantage))
+            .sum()
+    };
+    let mut low = -1.0;
+    let mut high = 1.0;
+    let mut widenings = 0;
+    while expected_score(low) > score {
+        if widenings == constants::MAX_ITERATIONS {
+            return None;
+        }
+        high = low;
+        low *= 2.0;
+        widenings += 1;
+    }
+    while expected_score(high) < score {
+        if widenings == constants::MAX_ITERATIONS {
+            return None;
+        }
+        low = high;
+        high *= 2.0;
+        widenings += 1;
+    }
+    for _ in 0..constants::MAX_ITERATIONS {
+        if high - low <= constants::EPSILON {
+            break;
+        }
+        let middle = (low + high) / 2.0;
+        if expected_score(middle) < score {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some((low + high) / 2.0 * constants::RATIO)
+}
+
+/// Determines the odds a team will beat each of a set of opponents, in order.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game};
+///
+/// let tuning = Tuning::default();
+///
+/// let rating = Rating::new(&tuning);
+/// let opponents = vec![Rating::new(&tuning), Rating::new(&tuning)];
+///
+/// let odds = game::odds_batch(&rating, &opponents);
+/// assert_eq!(odds, vec![0.5, 0.5]);
+/// ```
+pub fn odds_batch(rating: &Rating, opponents: &[Rating]) -> Vec<f64> {
+    opponents
+        .iter()
+        .map(|opponent| odds(rating, opponent))
+        .collect()
 }
 
 /// Determines the quality of a matchup, where 1.0 (100%) is a perfect match.
+/// Neither rating is modified, so this can be used on shared, read-only ratings.
 ///
 /// # Example
 ///
@@ -62,12 +363,12 @@ pub fn odds(rating1: &mut Rating, rating2: &mut Rating) -> f64 {
 ///
 /// let tuning = Tuning::default();
 ///
-/// let mut rating_1 = Rating::new(&tuning);
-/// let mut rating_2 = Rating::new(&tuning);
+/// let rating_1 = Rating::new(&tuning);
+/// let rating_2 = Rating::new(&tuning);
 ///
-/// let quality = game::quality(&mut rating_1, &mut rating_2);
+/// let quality = game::quality(&rating_1, &rating_2);
 /// ```
-pub fn quality(rating1: &mut Rating, rating2: &mut Rating) -> f64 {
+pub fn quality(rating1: &Rating, rating2: &Rating) -> f64 {
     // 1.0 if perfect match
     let expected_score_1 = odds(rating1, rating2);
     let expected_score_2 = odds(rating2, rating1);
@@ -75,12 +376,313 @@ pub fn quality(rating1: &mut Rating, rating2: &mut Rating) -> f64 {
     1.0 - advantage.abs()
 }
 
+/// Determines the quality of a team's matchup against each of a set of opponents, in order.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game};
+///
+/// let tuning = Tuning::default();
+///
+/// let rating = Rating::new(&tuning);
+/// let opponents = vec![Rating::new(&tuning), Rating::new(&tuning)];
+///
+/// let quality = game::quality_batch(&rating, &opponents);
+/// assert_eq!(quality, vec![1.0, 1.0]);
+/// ```
+pub fn quality_batch(rating: &Rating, opponents: &[Rating]) -> Vec<f64> {
+    opponents
+        .iter()
+        .map(|opponent| quality(rating, opponent))
+        .collect()
+}
+
+/// How both teams' ratings would move for one outcome of a game.
+///
+/// - `first`: Change for the first team
+/// - `second`: Change for the second team
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RatingChanges {
+    pub first: RatingChange,
+    pub second: RatingChange,
+}
+
+/// How both teams' ratings would move for every outcome of a game, from the first team's
+/// perspective.
+///
+/// - `win`: Changes if the first team wins
+/// - `draw`: Changes if the game is drawn
+/// - `loss`: Changes if the first team loses
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchPreview {
+    pub win: RatingChanges,
+    pub draw: RatingChanges,
+    pub loss: RatingChanges,
+}
+
+/// Previews how both ratings would move for each outcome of a game, as if it were the only
+/// game either team played this period, without changing either rating.
+/// Use [`RatingPeriod::preview`](crate::RatingPeriod::preview) for a whole period of games.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_preview`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game};
+///
+/// let tuning = Tuning::default();
+///
+/// let rating_1 = Rating::new(&tuning);
+/// let mut rating_2 = Rating::new(&tuning);
+/// rating_2.mu = 1600.0;
+///
+/// // Show "+N / -M" before queueing
+/// let preview = game::preview(&rating_1, &rating_2);
+/// assert!(preview.win.first.mu > 0.0);
+/// assert!(preview.loss.first.mu < 0.0);
+/// assert!(preview.win.first.mu > -preview.loss.first.mu);
+/// ```
+pub fn preview(rating1: &Rating, rating2: &Rating) -> MatchPreview {
+    match try_preview(rating1, rating2) {
+        Ok(preview) => preview,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`preview`].
+pub fn try_preview(rating1: &Rating, rating2: &Rating) -> Result<MatchPreview, GlickoError> {
+    let changes = |first_won: bool, drawn: bool| -> Result<RatingChanges, GlickoError> {
+        let mut first = *rating1;
+        let mut second = *rating2;
+        if first_won {
+            try_compete(&mut first, &mut second, drawn)?;
+        } else {
+            try_compete(&mut second, &mut first, drawn)?;
+        }
+        Ok(RatingChanges {
+            first: RatingChange::between(rating1, &first),
+            second: RatingChange::between(rating2, &second),
+        })
+    };
+    Ok(MatchPreview {
+        win: changes(true, false)?,
+        draw: changes(true, true)?,
+        loss: changes(false, false)?,
+    })
+}
+
+/// Separate probabilities of the first team winning, drawing, and losing a game.
+///
+/// - `win`: Probability the first team wins
+/// - `draw`: Probability the game is drawn
+/// - `loss`: Probability the first team loses
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutcomeProbabilities {
+    pub win: f64,
+    pub draw: f64,
+    pub loss: f64,
+}
+
+impl OutcomeProbabilities {
+    /// The expected score for the first team, counting a draw as half a win
+    pub fn expected_score(&self) -> f64 {
+        self.win * constants::WIN + self.draw * constants::DRAW + self.loss * constants::LOSS
+    }
+}
+
+/// Determines the probability of each outcome for the first team using Davidson's model,
+/// with the draw parameter from the first team's [`Tuning`].
+/// With a draw parameter of zero, the win probability matches [`odds`].
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game};
+///
+/// let tuning = Tuning::default().with_draw_parameter(1.0);
+///
+/// let rating_1 = Rating::new(&tuning);
+/// let rating_2 = Rating::new(&tuning);
+///
+/// let probabilities = game::outcome_probabilities(&rating_1, &rating_2);
+/// assert!((probabilities.draw - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn outcome_probabilities(rating1: &Rating, rating2: &Rating) -> OutcomeProbabilities {
+    match try_outcome_probabilities(rating1, rating2) {
+        Ok(probabilities) => probabilities,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`outcome_probabilities`].
+pub fn try_outcome_probabilities(
+    rating1: &Rating,
+    rating2: &Rating,
+) -> Result<OutcomeProbabilities, GlickoError> {
+    rating1.validate()?;
+    rating2.validate()?;
+    let strength = davidson_strength(rating1, rating2);
+    let draw_parameter = rating1.tuning().draw_parameter;
+    // Davidson's model: each side's weight is e^(±s/2), and draws get ν
+    let win = libm::exp(strength / 2.0);
+    let loss = libm::exp(-strength / 2.0);
+    let total = win + loss + draw_parameter;
+    Ok(OutcomeProbabilities {
+        win: win / total,
+        draw: draw_parameter / total,
+        loss: loss / total,
+    })
+}
+
+/// The Glicko2 scale strength difference g(φ) * (μ1 - μ2) used by Davidson's model.
+fn davidson_strength(rating1: &Rating, rating2: &Rating) -> f64 {
+    let rating1 = rating1.to_glicko2();
+    let rating2 = rating2.to_glicko2();
+    algorithm::prediction_impact(&rating1, &rating2) * (rating1.mu - rating2.mu)
+}
+
+/// Estimates the draw parameter for [`outcome_probabilities`] by maximum likelihood from past
+/// games, given as the pre-game ratings of both teams and the outcome for the first team.
+/// Partial [`Outcome::Score`] results other than `0`, `0.5` and `1` are ignored, as are games
+/// where either rating is invalid.
+///
+/// Returns `None` if there are no usable games, if every game was a draw, or if no draw
+/// parameter can explain the observed draws.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::{self, Outcome}};
+///
+/// let tuning = Tuning::default();
+/// let rating = Rating::new(&tuning);
+///
+/// let history = vec![
+///     (rating, rating, Outcome::Win),
+///     (rating, rating, Outcome::Draw),
+///     (rating, rating, Outcome::Loss),
+/// ];
+///
+/// // A third of even games were drawn, so ν / (2 + ν) = 1 / 3
+/// let draw_parameter = game::fit_draw_parameter(&history).unwrap();
+/// assert!((draw_parameter - 1.0).abs() < 1e-5);
+/// ```
+pub fn fit_draw_parameter(history: &[(Rating, Rating, Outcome)]) -> Option<f64> {
+    let mut draws = 0.0;
+    // 2cosh(s/2), the combined weight of the decisive outcomes for each game
+    let mut decisive_weights = Vec::with_capacity(history.len());
+    for (rating1, rating2, outcome) in history {
+        if rating1.validate().is_err() || rating2.validate().is_err() {
+            continue;
+        }
+        let score = outcome.val();
+        if score == constants::DRAW {
+            draws += 1.0;
+        } else if score != constants::WIN && score != constants::LOSS {
+            continue;
+        }
+        let strength = davidson_strength(rating1, rating2);
+        decisive_weights.push(libm::exp(strength / 2.0) + libm::exp(-strength / 2.0));
+    }
+
+    let games = decisive_weights.len() as f64;
+    if games == 0.0 || draws == games {
+        return None;
+    }
+    if draws == 0.0 {
+        return Some(0.0);
+    }
+
+    // The likelihood is maximized where the expected number of draws matches the observed
+    // number. Expected draws increase with ν, so bracket the root and bisect.
+    let expected_draws = |draw_parameter: f64| -> f64 {
+        decisive_weights
+            .iter()
+            .map(|weight| draw_parameter / (weight + draw_parameter))
+            .sum()
+    };
+    let mut low = 0.0;
+    let mut high = 1.0;
+    let mut widenings = 0;
+    while expected_draws(high) < draws {
+        if widenings == constants::MAX_ITERATIONS {
+            return None;
+        }
+        low = high;
+        high *= 2.0;
+        widenings += 1;
+    }
+    for _ in 0..constants::MAX_ITERATIONS {
+        let middle = (low + high) / 2.0;
+        if high - low <= constants::EPSILON * middle {
+            break;
+        }
+        if expected_draws(middle) < draws {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+/// Which side of a game a team played on, from that team's point of view.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    /// Neither team had an advantage
+    #[default]
+    Neutral,
+    /// The team was at home or moved first, and gets the advantage
+    Home,
+    /// The opponent was at home or moved first, and gets the advantage
+    Away,
+}
+
+impl Side {
+    /// Gets the same side from the opponent's point of view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::game::Side;
+    ///
+    /// assert_eq!(Side::Home.reverse(), Side::Away);
+    /// ```
+    pub fn reverse(&self) -> Side {
+        match self {
+            Side::Neutral => Side::Neutral,
+            Side::Home => Side::Away,
+            Side::Away => Side::Home,
+        }
+    }
+
+    /// The advantage this side gets on the Glicko2 scale.
+    pub(crate) fn advantage(&self, tuning: &Tuning) -> f64 {
+        match self {
+            Side::Neutral => 0.0,
+            Side::Home => tuning.advantage / constants::RATIO,
+            Side::Away => -tuning.advantage / constants::RATIO,
+        }
+    }
+}
+
 /// Enum representing the Glicko2 values for match outcomes
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Outcome {
     Win,
     Draw,
     Loss,
+    /// A partial result in `[0, 1]`, such as `2.5 / 4` games taken in a best-of-four
+    Score(f64),
 }
 
 impl Outcome {
@@ -99,6 +701,39 @@ impl Outcome {
             Outcome::Win => constants::WIN,
             Outcome::Draw => constants::DRAW,
             Outcome::Loss => constants::LOSS,
+            Outcome::Score(score) => *score,
+        }
+    }
+
+    /// Whether the outcome's value lies within `[0, 1]`, the range the algorithm is defined for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::game::Outcome;
+    ///
+    /// assert!(Outcome::Score(0.625).is_valid());
+    /// assert!(!Outcome::Score(1.5).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        (constants::LOSS..=constants::WIN).contains(&self.val())
+    }
+
+    /// Gets the same result from the opponent's point of view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::game::Outcome;
+    ///
+    /// assert_eq!(Outcome::Win.reverse(), Outcome::Loss);
+    /// ```
+    pub fn reverse(&self) -> Outcome {
+        match self {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Loss => Outcome::Win,
+            Outcome::Score(score) => Outcome::Score(constants::WIN - score),
         }
     }
 }
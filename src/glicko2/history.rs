@@ -0,0 +1,455 @@
+/*!
+Keeps every player's rating after each rating period, for time-travel queries
+*/
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::glicko2::{
+    error::GlickoError, game::Outcome, period::RatingPeriod, rating::Rating, system::RatingSystem,
+    tuning::Tuning,
+};
+
+/// A player's rating at the close of a rating period.
+///
+/// - `timestamp`: When the period closed, in seconds since the Unix epoch (UTC)
+/// - `rating`: The rating after the period was rated
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub timestamp: i64,
+    pub rating: Rating,
+}
+
+/// The games rated in a closed rating period.
+///
+/// - `timestamp`: When the period closed, in seconds since the Unix epoch (UTC)
+/// - `games`: The games as `(player, opponent, outcome, weight)`, in the order they were added
+/// - `span`: How many rating periods were closed, more than one for a run of empty periods
+///   decayed at once with [`RatingHistory::decay_periods_at`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClosedPeriod<Id> {
+    pub timestamp: i64,
+    pub games: Vec<(Id, Id, Outcome, f64)>,
+    pub span: f64,
+}
+
+/// Everything kept about a single player.
+#[derive(Debug, Clone)]
+struct PlayerHistory {
+    /// The index of the first period the player was part of
+    joined: usize,
+    /// The player's rating at the start of that period
+    start: Rating,
+    /// The index of the period the first kept snapshot is from
+    first: usize,
+    /// One snapshot per period since `first`
+    snapshots: Vec<Snapshot>,
+}
+
+/// A [`RatingSystem`] that keeps a snapshot of every player's rating each time a period is
+/// closed, rather than only the latest rating.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Tuning, game::Outcome, history::RatingHistory};
+///
+/// let mut history = RatingHistory::new(Tuning::default());
+/// history.add_game("alice", "bob", Outcome::Win);
+/// history.close_period_at(100);
+/// history.add_game("alice", "bob", Outcome::Loss);
+/// history.close_period_at(200);
+///
+/// // Alice's rating after the first period, and every snapshot since
+/// assert!(history.rating_at(&"alice", 150).unwrap().mu > 1500.0);
+/// assert_eq!(history.trajectory(&"alice", 0, 200).len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RatingHistory<Id> {
+    system: RatingSystem<Id>,
+    players: HashMap<Id, PlayerHistory>,
+    periods: Vec<ClosedPeriod<Id>>,
+    /// Number of closed periods whose games were dropped by [`RatingHistory::retain_since`]
+    dropped: usize,
+    /// When the last period closed, kept even once its games are dropped
+    last_close: Option<i64>,
+}
+
+impl<Id> RatingHistory<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    /// Create an empty history whose players are rated with `tuning`.
+    pub fn new(tuning: Tuning) -> Self {
+        Self::from_system(RatingSystem::new(tuning))
+    }
+
+    /// Start keeping history for an existing system. Its current ratings have no snapshots
+    /// until the next period is closed.
+    pub fn from_system(system: RatingSystem<Id>) -> Self {
+        Self {
+            system,
+            players: HashMap::new(),
+            periods: Vec::new(),
+            dropped: 0,
+            last_close: None,
+        }
+    }
+
+    /// The current ratings, along with the games recorded during the current period.
+    pub fn system(&self) -> &RatingSystem<Id> {
+        &self.system
+    }
+
+    /// Stop keeping history, returning the current ratings.
+    pub fn into_system(self) -> RatingSystem<Id> {
+        self.system
+    }
+
+    /// Register a player. See [`RatingSystem::add_player`].
+    pub fn add_player(&mut self, id: Id) -> &Rating {
+        self.system.add_player(id)
+    }
+
+    /// Record a game in the current period. See [`RatingSystem::add_game`].
+    pub fn add_game(&mut self, player: Id, opponent: Id, outcome: Outcome) {
+        self.system.add_game(player, opponent, outcome);
+    }
+
+    /// Fallible version of [`RatingHistory::add_game`]. See [`RatingSystem::try_add_game`].
+    pub fn try_add_game(
+        &mut self,
+        player: Id,
+        opponent: Id,
+        outcome: Outcome,
+    ) -> Result<(), GlickoError> {
+        self.system.try_add_game(player, opponent, outcome)
+    }
+
+    /// Record a weighted game in the current period. See [`RatingSystem::add_weighted_game`].
+    pub fn add_weighted_game(&mut self, player: Id, opponent: Id, outcome: Outcome, weight: f64) {
+        self.system
+            .add_weighted_game(player, opponent, outcome, weight);
+    }
+
+    /// Fallible version of [`RatingHistory::add_weighted_game`].
+    /// See [`RatingSystem::try_add_weighted_game`].
+    pub fn try_add_weighted_game(
+        &mut self,
+        player: Id,
+        opponent: Id,
+        outcome: Outcome,
+        weight: f64,
+    ) -> Result<(), GlickoError> {
+        self.system
+            .try_add_weighted_game(player, opponent, outcome, weight)
+    }
+
+    /// Close the current period at `timestamp`, keeping a snapshot of every player's new rating.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingHistory::try_close_period_at`] would return.
+    pub fn close_period_at(&mut self, timestamp: i64) {
+        if let Err(error) = self.try_close_period_at(timestamp) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingHistory::close_period_at`], which also fails if `timestamp`
+    /// is before the previous close. Nothing is changed if an error is returned.
+    pub fn try_close_period_at(&mut self, timestamp: i64) -> Result<(), GlickoError> {
+        // Keep what is needed to recompute this period later, see `try_invalidate`
+        let games = self.system.period().games().to_vec();
+        self.try_record(timestamp, games, 1.0, RatingSystem::try_close_period)
+    }
+
+    /// Decay every player as if `periods` rating periods passed without any games, keeping a
+    /// single snapshot at `timestamp` for all of them. See [`RatingSystem::decay_periods`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingHistory::try_decay_periods_at`] would return.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, game::Outcome, history::RatingHistory};
+    ///
+    /// let mut history = RatingHistory::new(Tuning::default());
+    /// history.add_game("alice", "bob", Outcome::Win);
+    /// history.close_period_at(100);
+    ///
+    /// // Nobody played for the next ten periods
+    /// history.decay_periods_at(1_100, 10.0);
+    ///
+    /// let snapshots = history.snapshots(&"alice");
+    /// assert_eq!(snapshots.len(), 2);
+    /// assert!(snapshots[1].rating.phi > snapshots[0].rating.phi);
+    /// ```
+    pub fn decay_periods_at(&mut self, timestamp: i64, periods: f64) {
+        if let Err(error) = self.try_decay_periods_at(timestamp, periods) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingHistory::decay_periods_at`], which also fails if
+    /// `timestamp` is before the previous close. Nothing is changed if an error is returned.
+    pub fn try_decay_periods_at(
+        &mut self,
+        timestamp: i64,
+        periods: f64,
+    ) -> Result<(), GlickoError> {
+        self.try_record(timestamp, Vec::new(), periods, |system| {
+            system.try_decay_periods(periods)
+        })
+    }
+
+    /// Apply `update` to the system, then keep a snapshot of every player's new rating and
+    /// the games needed to recompute it.
+    fn try_record(
+        &mut self,
+        timestamp: i64,
+        games: Vec<(Id, Id, Outcome, f64)>,
+        span: f64,
+        update: impl FnOnce(&mut RatingSystem<Id>) -> Result<(), GlickoError>,
+    ) -> Result<(), GlickoError> {
+        if self.last_close.is_some_and(|last| timestamp < last) {
+            return Err(GlickoError::OutOfOrder);
+        }
+        let index = self.dropped + self.periods.len();
+        let joining: HashMap<Id, Rating> = self
+            .system
+            .iter()
+            .filter(|(id, _)| !self.players.contains_key(id))
+            .map(|(id, rating)| (id.clone(), *rating))
+            .collect();
+        update(&mut self.system)?;
+
+        for (id, rating) in &self.system {
+            let player = self
+                .players
+                .entry(id.clone())
+                .or_insert_with(|| PlayerHistory {
+                    joined: index,
+                    start: joining[id],
+                    first: index,
+                    snapshots: Vec::new(),
+                });
+            player.snapshots.push(Snapshot {
+                timestamp,
+                rating: *rating,
+            });
+        }
+        self.periods.push(ClosedPeriod {
+            timestamp,
+            games,
+            span,
+        });
+        self.last_close = Some(timestamp);
+        Ok(())
+    }
+
+    /// Every snapshot kept for a player, oldest first.
+    pub fn snapshots(&self, id: &Id) -> &[Snapshot] {
+        self.players
+            .get(id)
+            .map_or(&[], |player| player.snapshots.as_slice())
+    }
+
+    /// The games of every closed period that has not been dropped, oldest first.
+    pub fn periods(&self) -> &[ClosedPeriod<Id>] {
+        &self.periods
+    }
+
+    /// A player's rating as of `timestamp`, from the last period closed at or before it.
+    /// Returns `None` if no period that included the player had closed by then.
+    pub fn rating_at(&self, id: &Id, timestamp: i64) -> Option<&Rating> {
+        let snapshots = self.snapshots(id);
+        let closed = snapshots.partition_point(|snapshot| snapshot.timestamp <= timestamp);
+        closed.checked_sub(1).map(|index| &snapshots[index].rating)
+    }
+
+    /// A player's snapshots from periods closed between `from` and `to`, inclusive.
+    pub fn trajectory(&self, id: &Id, from: i64, to: i64) -> &[Snapshot] {
+        let snapshots = self.snapshots(id);
+        let start = snapshots.partition_point(|snapshot| snapshot.timestamp < from);
+        let end = snapshots.partition_point(|snapshot| snapshot.timestamp <= to);
+        &snapshots[start..end.max(start)]
+    }
+
+    /// Drop snapshots and games from periods closed before `horizon`. Each player's last
+    /// snapshot before the horizon is kept, so [`RatingHistory::rating_at`] still answers for
+    /// any time from the horizon on.
+    pub fn retain_since(&mut self, horizon: i64) {
+        for player in self.players.values_mut() {
+            let before = player
+                .snapshots
+                .partition_point(|snapshot| snapshot.timestamp < horizon);
+            let drop = before.saturating_sub(1);
+            player.snapshots.drain(..drop);
+            player.first += drop;
+        }
+        let before = self
+            .periods
+            .partition_point(|period| period.timestamp < horizon);
+        self.periods.drain(..before);
+        self.dropped += before;
+    }
+
+    /// Remove a game found to be fixed or mis-reported, given by its index in
+    /// [`RatingHistory::periods`] and its index in that period's games, then recompute every
+    /// later period. Only players whose rating could have changed are recomputed: the two
+    /// players in the game, then anyone who played a recomputed player in a later period.
+    /// Snapshots and current ratings are updated to match a replay of the corrected log.
+    /// Players stay registered even if the removed game was their only one.
+    ///
+    /// Returns the players whose ratings were recomputed.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingHistory::try_invalidate`] would return.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, game::Outcome, history::RatingHistory};
+    ///
+    /// let mut history = RatingHistory::new(Tuning::default());
+    /// history.add_game("alice", "bob", Outcome::Win);
+    /// history.add_game("carol", "dave", Outcome::Win);
+    /// history.close_period_at(100);
+    /// history.add_game("bob", "carol", Outcome::Win);
+    /// history.close_period_at(200);
+    ///
+    /// // Alice's win turned out to be fixed
+    /// let recomputed = history.invalidate(0, 0);
+    /// assert!(recomputed.contains(&"carol"));
+    /// assert!(!recomputed.contains(&"dave"));
+    /// assert_eq!(history.system().get(&"alice").unwrap().mu, 1500.0);
+    /// ```
+    pub fn invalidate(&mut self, period: usize, game: usize) -> HashSet<Id> {
+        match self.try_invalidate(period, game) {
+            Ok(recomputed) => recomputed,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Fallible version of [`RatingHistory::invalidate`], which also fails if there is no such
+    /// game. Nothing is changed if an error is returned.
+    pub fn try_invalidate(
+        &mut self,
+        period: usize,
+        game: usize,
+    ) -> Result<HashSet<Id>, GlickoError> {
+        let (player, opponent, ..) = self
+            .periods
+            .get(period)
+            .and_then(|closed| closed.games.get(game))
+            .ok_or(GlickoError::UnknownGame)?
+            .clone();
+
+        // Players whose rating at the start of the period being recomputed has changed
+        let mut changed: HashSet<Id> = HashSet::new();
+        // The latest recomputed rating of every changed player
+        let mut recomputed: HashMap<Id, Rating> = HashMap::new();
+        let mut updates = Vec::new();
+
+        for (offset, closed) in self.periods[period..].iter().enumerate() {
+            let index = self.dropped + period + offset;
+            let games: Vec<_> = closed
+                .games
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| offset != 0 || *position != game)
+                .map(|(_, game)| game)
+                .collect();
+
+            // Anyone who played a changed player saw a different opponent rating
+            let mut recompute = changed.clone();
+            if offset == 0 {
+                recompute.insert(player.clone());
+                recompute.insert(opponent.clone());
+            }
+            for (a, b, ..) in &games {
+                if changed.contains(a) {
+                    recompute.insert(b.clone());
+                }
+                if changed.contains(b) {
+                    recompute.insert(a.clone());
+                }
+            }
+
+            // Rate the recomputed players against everyone's start of period ratings
+            let mut partial = RatingPeriod::new();
+            let mut ratings = HashMap::new();
+            for (a, b, outcome, weight) in games {
+                if recompute.contains(a) || recompute.contains(b) {
+                    partial.add_weighted_game(a.clone(), b.clone(), *outcome, *weight);
+                    for id in [a, b] {
+                        if !ratings.contains_key(id) {
+                            ratings.insert(id.clone(), self.start_of(id, index, &recomputed));
+                        }
+                    }
+                }
+            }
+            for id in &recompute {
+                if !ratings.contains_key(id) {
+                    ratings.insert(id.clone(), self.start_of(id, index, &recomputed));
+                }
+            }
+            if partial.is_empty() {
+                // Without games the recomputed players only decay, possibly for several periods
+                for rating in ratings.values_mut() {
+                    rating.try_decay_periods(closed.span)?;
+                }
+            } else {
+                partial.try_rate(&mut ratings)?;
+            }
+
+            for id in &recompute {
+                let rating = ratings[id];
+                recomputed.insert(id.clone(), rating);
+                updates.push((id.clone(), index, rating));
+            }
+            changed = recompute;
+        }
+
+        // Every update succeeded, so write them all
+        self.periods[period].games.remove(game);
+        for (id, index, rating) in updates {
+            let player = self
+                .players
+                .get_mut(&id)
+                .expect("recomputed players have history");
+            player.snapshots[index - player.first].rating = rating;
+        }
+        for (id, rating) in &recomputed {
+            self.system.insert(id.clone(), *rating);
+        }
+        Ok(recomputed.into_keys().collect())
+    }
+
+    /// A player's rating at the start of the period at `index`, preferring any rating
+    /// recomputed for the period before it.
+    fn start_of(&self, id: &Id, index: usize, recomputed: &HashMap<Id, Rating>) -> Rating {
+        if let Some(rating) = recomputed.get(id) {
+            return *rating;
+        }
+        let player = &self.players[id];
+        if index == player.joined {
+            player.start
+        } else {
+            // Retention always keeps the snapshot from just before the oldest kept period
+            player.snapshots[index - 1 - player.first].rating
+        }
+    }
+
+    /// Mutable access to the current ratings, for closing periods without taking snapshots.
+    pub(crate) fn system_mut(&mut self) -> &mut RatingSystem<Id> {
+        &mut self.system
+    }
+}
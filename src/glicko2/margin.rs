@@ -0,0 +1,114 @@
+/*!
+Margin of victory scaling, so decisive wins move ratings further than narrow ones
+*/
+use crate::glicko2::{
+    constants::{MARGIN_AUTOCORRELATION, MARGIN_MAX_MULTIPLIER, MARGIN_MIN_MULTIPLIER, RATIO},
+    error::GlickoError,
+    rating::{validate_non_negative, validate_positive},
+};
+
+/// Maps the size of a winning margin onto a multiplier, before correction and bounds.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarginLink {
+    /// `log2(1 + margin)`, so a one point win counts as a regular win
+    Logarithmic,
+    /// `sqrt(margin)`, so a one point win counts as a regular win
+    SquareRoot,
+    /// `margin / scale`, so a win by `scale` points counts as a regular win
+    Linear(f64),
+}
+
+impl MarginLink {
+    fn apply(&self, margin: f64) -> f64 {
+        match self {
+            MarginLink::Logarithmic => libm::log1p(margin) / std::f64::consts::LN_2,
+            MarginLink::SquareRoot => margin.sqrt(),
+            MarginLink::Linear(scale) => margin / scale,
+        }
+    }
+}
+
+/// Configuration for margin of victory aware updates.
+///
+/// - `link`: How the winning margin maps onto a multiplier
+/// - `autocorrelation`: How much to shrink the multiplier per rating point the winner was
+///   favored by, so favorites are not over-rewarded for the large margins they are expected
+///   to win by
+/// - `min_multiplier`: The smallest multiplier a game can get
+/// - `max_multiplier`: The largest multiplier a game can get
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MarginOfVictory {
+    pub link: MarginLink,
+    pub autocorrelation: f64,
+    pub min_multiplier: f64,
+    pub max_multiplier: f64,
+}
+
+impl MarginOfVictory {
+    /// Create a custom margin of victory configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::margin::{MarginLink, MarginOfVictory};
+    ///
+    /// let default_margin = MarginOfVictory::default();
+    /// let custom_margin = MarginOfVictory::new(MarginLink::Linear(3.0), 0.0005, 0.5, 2.0);
+    /// ```
+    pub const fn new(
+        link: MarginLink,
+        autocorrelation: f64,
+        min_multiplier: f64,
+        max_multiplier: f64,
+    ) -> Self {
+        Self {
+            link,
+            autocorrelation,
+            min_multiplier,
+            max_multiplier,
+        }
+    }
+
+    /// Checks that the link scale and maximum multiplier are positive, that the
+    /// autocorrelation and minimum multiplier are not negative, and that the minimum
+    /// multiplier does not exceed the maximum.
+    pub(crate) fn validate(&self) -> Result<(), GlickoError> {
+        if let MarginLink::Linear(scale) = self.link {
+            validate_positive("tuning.margin.link", scale)?;
+        }
+        validate_non_negative("tuning.margin.autocorrelation", self.autocorrelation)?;
+        validate_non_negative("tuning.margin.min_multiplier", self.min_multiplier)?;
+        validate_positive("tuning.margin.max_multiplier", self.max_multiplier)?;
+        if self.min_multiplier > self.max_multiplier {
+            return Err(GlickoError::InvalidRange("tuning.margin.min_multiplier"));
+        }
+        Ok(())
+    }
+
+    /// The multiplier for a game won by `margin`, where `favored_by` is the winner's rating
+    /// lead over the loser on the Glicko2 scale.
+    pub(crate) fn multiplier(&self, margin: f64, favored_by: f64) -> f64 {
+        let correction = 1.0 + self.autocorrelation * favored_by * RATIO;
+        let multiplier = if correction > 0.0 {
+            self.link.apply(margin) / correction
+        } else {
+            // Only a huge upset gets here, which deserves the largest multiplier
+            self.max_multiplier
+        };
+        multiplier.max(self.min_multiplier).min(self.max_multiplier)
+    }
+}
+
+impl Default for MarginOfVictory {
+    fn default() -> Self {
+        Self::new(
+            MarginLink::Logarithmic,
+            MARGIN_AUTOCORRELATION,
+            MARGIN_MIN_MULTIPLIER,
+            MARGIN_MAX_MULTIPLIER,
+        )
+    }
+}
@@ -3,6 +3,16 @@ Container for Glicko2 algorithm and convenience methods
 */
 pub mod algorithm;
 pub mod constants;
+pub mod elo;
+pub mod error;
 pub mod game;
+pub mod history;
+pub mod margin;
+pub mod multiplayer;
+pub mod period;
 pub mod rating;
+pub mod replay;
+pub mod solver;
+pub mod system;
+pub mod team;
 pub mod tuning;
@@ -0,0 +1,154 @@
+/*!
+Provides functions to rate free-for-all games by splitting a finish order into pairwise games
+*/
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::glicko2::{error::GlickoError, game::Outcome, period::RatingPeriod, rating::Rating};
+
+/// How much each pairwise game from a free-for-all counts towards a player's rating.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Weighting {
+    /// Every pair counts as a full game, so a lobby of `N` players counts as `N - 1` games
+    Full,
+    /// Every pair counts as `1 / (N - 1)` of a game, so a lobby counts as one game per player
+    #[default]
+    Normalized,
+    /// Every pair counts as the given weight
+    Fixed(f64),
+}
+
+impl Weighting {
+    /// The weight of a single pairwise game in a lobby of `players` players.
+    fn weight(&self, players: usize) -> f64 {
+        match self {
+            Weighting::Full => 1.0,
+            Weighting::Normalized => 1.0 / (players - 1) as f64,
+            Weighting::Fixed(weight) => *weight,
+        }
+    }
+}
+
+/// Record a free-for-all finish order in a rating period as one weighted game per pair of
+/// players. Lower placements finish ahead of higher ones, and equal placements are draws.
+/// Each player should appear only once; see [`try_rate_placements`] for a validated version.
+/// Lobbies with fewer than two players have no games to record.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use glicko_2::{Rating, Tuning, RatingPeriod, multiplayer::{self, Weighting}};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut ratings = HashMap::new();
+/// for id in ["alice", "bob", "carol", "dave"] {
+///     ratings.insert(id, Rating::new(&tuning));
+/// }
+///
+/// // Two lobbies played during the same rating period
+/// let mut period = RatingPeriod::new();
+/// multiplayer::add_placements(&mut period, &[("alice", 1), ("bob", 2), ("carol", 3)], Weighting::Normalized);
+/// multiplayer::add_placements(&mut period, &[("dave", 1), ("alice", 2)], Weighting::Normalized);
+/// assert_eq!(period.len(), 4);
+///
+/// period.rate(&mut ratings);
+/// ```
+pub fn add_placements<Id>(
+    period: &mut RatingPeriod<Id>,
+    placements: &[(Id, usize)],
+    weighting: Weighting,
+) where
+    Id: Eq + Hash + Clone,
+{
+    if placements.len() < 2 {
+        return;
+    }
+    let weight = weighting.weight(placements.len());
+    for (index, (player, placement)) in placements.iter().enumerate() {
+        for (opponent, other_placement) in &placements[index + 1..] {
+            let outcome = match placement.cmp(other_placement) {
+                std::cmp::Ordering::Less => Outcome::Win,
+                std::cmp::Ordering::Equal => Outcome::Draw,
+                std::cmp::Ordering::Greater => Outcome::Loss,
+            };
+            period.add_weighted_game(player.clone(), opponent.clone(), outcome, weight);
+        }
+    }
+}
+
+/// Updates the rating of every player in a free-for-all game from their finish order.
+/// Each player is rated once against every other player's pre-game rating, and players in
+/// `ratings` who were not in the lobby are left untouched.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_rate_placements`] would return.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use glicko_2::{Rating, Tuning, multiplayer::{self, Weighting}};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut ratings = HashMap::new();
+/// for id in ["alice", "bob", "carol", "dave"] {
+///     ratings.insert(id, Rating::new(&tuning));
+/// }
+///
+/// // Bob and Carol tied for second
+/// multiplayer::rate_placements(
+///     &[("alice", 1), ("bob", 2), ("carol", 2), ("dave", 4)],
+///     Weighting::Normalized,
+///     &mut ratings,
+/// );
+/// assert!(ratings["alice"].mu > ratings["bob"].mu);
+/// assert_eq!(ratings["bob"].mu, ratings["carol"].mu);
+/// ```
+pub fn rate_placements<Id>(
+    placements: &[(Id, usize)],
+    weighting: Weighting,
+    ratings: &mut HashMap<Id, Rating>,
+) where
+    Id: Eq + Hash + Clone,
+{
+    if let Err(error) = try_rate_placements(placements, weighting, ratings) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate_placements`]. No rating is changed if an error is returned.
+pub fn try_rate_placements<Id>(
+    placements: &[(Id, usize)],
+    weighting: Weighting,
+    ratings: &mut HashMap<Id, Rating>,
+) -> Result<(), GlickoError>
+where
+    Id: Eq + Hash + Clone,
+{
+    if placements.len() < 2 {
+        return Err(GlickoError::NoOutcomes);
+    }
+    let mut seen = HashSet::with_capacity(placements.len());
+    let mut lobby = HashMap::with_capacity(placements.len());
+    for (player, _) in placements {
+        if !seen.insert(player) {
+            return Err(GlickoError::DuplicatePlayer);
+        }
+        let rating = *ratings.get(player).ok_or(GlickoError::UnknownPlayer)?;
+        lobby.insert(player.clone(), rating);
+    }
+
+    // Only the lobby is rated, so players outside it are not decayed
+    let mut period = RatingPeriod::new();
+    add_placements(&mut period, placements, weighting);
+    period.try_rate(&mut lobby)?;
+    ratings.extend(lobby);
+    Ok(())
+}
@@ -0,0 +1,250 @@
+/*!
+Collects every game played during a rating period and applies the updates simultaneously
+*/
+use std::{collections::HashMap, hash::Hash};
+
+use crate::glicko2::{
+    algorithm,
+    error::GlickoError,
+    game::Outcome,
+    rating::{ProvisionalRating, Rating, RatingChange},
+};
+
+/// A rating period, the unit of time over which Glicko2 treats all games as concurrent.
+///
+/// Games are recorded as they happen; nothing is updated until [`RatingPeriod::rate`] is called.
+/// At that point every rating is snapshotted, each player's new rating is computed only from
+/// those pre-period snapshots, and all of the results are written back at once. Players in
+/// the ratings map who did not play during the period are decayed.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use glicko_2::{Rating, Tuning, game::Outcome, period::RatingPeriod};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut ratings = HashMap::new();
+/// ratings.insert("alice", Rating::new(&tuning));
+/// ratings.insert("bob", Rating::new(&tuning));
+/// ratings.insert("carol", Rating::new(&tuning));
+///
+/// let mut period = RatingPeriod::new();
+/// period.add_game("alice", "bob", Outcome::Win);
+/// period.add_game("bob", "alice", Outcome::Draw);
+///
+/// // Alice and Bob are rated against each other's pre-period ratings, Carol is decayed
+/// period.rate(&mut ratings);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RatingPeriod<Id> {
+    games: Vec<(Id, Id, Outcome, f64)>,
+}
+
+impl<Id> RatingPeriod<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    /// Create an empty rating period.
+    pub fn new() -> Self {
+        Self { games: Vec::new() }
+    }
+
+    /// Record a game between `player` and `opponent`, where `outcome` is from the perspective
+    /// of `player`. Both sides of the game are rated when the period is closed.
+    pub fn add_game(&mut self, player: Id, opponent: Id, outcome: Outcome) {
+        self.add_weighted_game(player, opponent, outcome, 1.0);
+    }
+
+    /// Record a game that counts `weight` times as much as a regular game, for example
+    /// a cup final compared to a scrim. See [`algorithm::rate_weighted`].
+    pub fn add_weighted_game(&mut self, player: Id, opponent: Id, outcome: Outcome, weight: f64) {
+        self.games.push((player, opponent, outcome, weight));
+    }
+
+    /// The games recorded so far as `(player, opponent, outcome, weight)`, in the order they
+    /// were added.
+    pub fn games(&self) -> &[(Id, Id, Outcome, f64)] {
+        &self.games
+    }
+
+    /// Number of games recorded so far.
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Whether no games have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Close the period, updating every rating in `ratings` from the pre-period snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingPeriod::try_rate`] would return.
+    pub fn rate(&self, ratings: &mut HashMap<Id, Rating>) {
+        if let Err(error) = self.try_rate(ratings) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingPeriod::rate`], which also fails if a player was recorded
+    /// playing against themselves. No rating is changed if an error is returned.
+    pub fn try_rate(&self, ratings: &mut HashMap<Id, Rating>) -> Result<(), GlickoError> {
+        let updated = self.updated(ratings, true)?;
+        ratings.extend(updated);
+        Ok(())
+    }
+
+    /// Estimate every rating from the games recorded so far, without closing the period or
+    /// changing `ratings`. Players who have not played yet keep their pre-period rating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use glicko_2::{Rating, Tuning, game::Outcome, period::RatingPeriod};
+    ///
+    /// let tuning = Tuning::default();
+    ///
+    /// let mut ratings = HashMap::new();
+    /// ratings.insert("alice", Rating::new(&tuning));
+    /// ratings.insert("bob", Rating::new(&tuning));
+    ///
+    /// let mut period = RatingPeriod::new();
+    /// period.add_game("alice", "bob", Outcome::Win);
+    ///
+    /// // Show live movement on the ladder; the official update happens at period close
+    /// let live = period.provisional_ratings(&ratings).unwrap();
+    /// assert!(live["alice"].mu() > ratings["alice"].mu);
+    /// ```
+    pub fn provisional_ratings(
+        &self,
+        ratings: &HashMap<Id, Rating>,
+    ) -> Result<HashMap<Id, ProvisionalRating>, GlickoError> {
+        Ok(self
+            .updated(ratings, false)?
+            .into_iter()
+            .map(|(id, rating)| (id, ProvisionalRating::new(&rating)))
+            .collect())
+    }
+
+    /// Preview how every rating would move if the period were closed now, without changing
+    /// `ratings`. Record candidate games in a separate period to preview them all at once.
+    /// Players who did not play are decayed, as they would be by [`RatingPeriod::rate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use glicko_2::{Rating, Tuning, game::Outcome, period::RatingPeriod};
+    ///
+    /// let tuning = Tuning::default();
+    ///
+    /// let mut ratings = HashMap::new();
+    /// ratings.insert("alice", Rating::new(&tuning));
+    /// ratings.insert("bob", Rating::new(&tuning));
+    /// ratings.insert("carol", Rating::new(&tuning));
+    ///
+    /// // What if Alice beats both Bob and Carol this period?
+    /// let mut candidate = RatingPeriod::new();
+    /// candidate.add_game("alice", "bob", Outcome::Win);
+    /// candidate.add_game("alice", "carol", Outcome::Win);
+    ///
+    /// let changes = candidate.preview(&ratings).unwrap();
+    /// assert!(changes["alice"].mu > 0.0);
+    /// assert!(changes["bob"].mu < 0.0);
+    /// assert_eq!(ratings["alice"].mu, 1500.0);
+    /// ```
+    pub fn preview(
+        &self,
+        ratings: &HashMap<Id, Rating>,
+    ) -> Result<HashMap<Id, RatingChange>, GlickoError> {
+        Ok(self
+            .updated(ratings, true)?
+            .into_iter()
+            .map(|(id, rating)| {
+                let change = RatingChange::between(&ratings[&id], &rating);
+                (id, change)
+            })
+            .collect())
+    }
+
+    /// Compute every player's rating from the pre-period snapshots without writing any,
+    /// optionally decaying players who did not play.
+    fn updated(
+        &self,
+        ratings: &HashMap<Id, Rating>,
+        decay_idle: bool,
+    ) -> Result<Vec<(Id, Rating)>, GlickoError> {
+        // Collect each player's games, in insertion order, from their own perspective
+        let mut schedule: HashMap<&Id, Vec<(Outcome, f64, Rating)>> = HashMap::new();
+        for (player, opponent, outcome, weight) in &self.games {
+            if player == opponent {
+                return Err(GlickoError::DuplicatePlayer);
+            }
+            let player_rating = *ratings.get(player).ok_or(GlickoError::UnknownPlayer)?;
+            let opponent_rating = *ratings.get(opponent).ok_or(GlickoError::UnknownPlayer)?;
+            schedule
+                .entry(player)
+                .or_default()
+                .push((*outcome, *weight, opponent_rating));
+            schedule
+                .entry(opponent)
+                .or_default()
+                .push((outcome.reverse(), *weight, player_rating));
+        }
+
+        // Compute every update before writing any, so each one only sees pre-period ratings
+        let mut updated = Vec::with_capacity(ratings.len());
+        for (id, rating) in ratings.iter() {
+            let mut rating = *rating;
+            match schedule.get_mut(id) {
+                Some(games) => {
+                    algorithm::try_rate_weighted(
+                        &mut rating,
+                        games
+                            .iter_mut()
+                            .map(|(outcome, weight, opponent)| (*outcome, *weight, opponent))
+                            .collect(),
+                    )?;
+                }
+                None if decay_idle => rating.decay(),
+                None => {}
+            }
+            updated.push((id.clone(), rating));
+        }
+        Ok(updated)
+    }
+}
+
+/// Checks that a game can be rated: the players differ, the outcome lies within `[0, 1]` and
+/// the weight is a positive finite number.
+pub(crate) fn validate_game<Id: PartialEq>(
+    player: &Id,
+    opponent: &Id,
+    outcome: Outcome,
+    weight: f64,
+) -> Result<(), GlickoError> {
+    if player == opponent {
+        return Err(GlickoError::DuplicatePlayer);
+    }
+    if !outcome.is_valid() {
+        return Err(GlickoError::InvalidOutcome(outcome.val()));
+    }
+    if !(weight.is_finite() && weight > 0.0) {
+        return Err(GlickoError::InvalidWeight(weight));
+    }
+    Ok(())
+}
+
+impl<Id> Default for RatingPeriod<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,19 +2,50 @@
 Data structures and convenience methods for creating and interacting with rating data
 */
 
-use crate::glicko2::{constants, tuning::Tuning};
+use std::{cmp::Ordering, time::Duration};
 
-/// Represents a team's Glicko2 rating (mu), distribution (phi), and volatility (sigma).
+use crate::glicko2::{
+    algorithm, constants,
+    error::GlickoError,
+    tuning::{Algorithm, Tuning},
+};
+
+/// Represents a team's Glicko2 rating (mu), distribution (phi), and volatility (sigma)
+/// on the nominal display scale, i.e. `1500 ± 350`.
+///
+/// A rating owns a copy of the tuning parameters it was created with, so it can be stored
+/// in long-lived collections and sent across threads without borrowing the [`Tuning`].
+///
+/// With the `serde` feature enabled, ratings are written with a format version tag:
+/// `{ "version": 1, "mu": ..., "phi": ..., "sigma": ..., "tuning": { ... } }`.
 #[derive(Debug, Copy, Clone)]
-pub struct Rating<'a> {
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "RatingRecord", try_from = "RatingRecord")
+)]
+pub struct Rating {
     pub mu: f64,
     pub phi: f64,
     pub sigma: f64,
-    pub is_scaled: bool,
-    pub(crate) tuning: &'a Tuning,
+    pub(crate) tuning: Tuning,
 }
 
-impl<'a> Rating<'a> {
+/// A rating on the nominal display scale; an alias for [`Rating`].
+pub type DisplayRating = Rating;
+
+/// Represents a team's rating on the Glicko2 scale (μ, φ), where the tuning's default
+/// rating maps to `0.0`. The algorithm works in this scale; convert with
+/// [`Rating::to_glicko2`] and [`Glicko2Rating::to_display`].
+#[derive(Debug, Copy, Clone)]
+pub struct Glicko2Rating {
+    pub mu: f64,
+    pub phi: f64,
+    pub sigma: f64,
+    pub(crate) tuning: Tuning,
+}
+
+impl Rating {
     /// Create a new instance of a Rating based on the provided tuning parameters.
     ///
     /// # Example
@@ -29,49 +60,486 @@ impl<'a> Rating<'a> {
             mu: tuning.mu,
             phi: tuning.phi,
             sigma: tuning.sigma,
-            is_scaled: false,
-            tuning,
+            tuning: *tuning,
         }
     }
 
-    /// Scales a rating down to the Glicko2 scale
-    pub(crate) fn scale_down(&mut self) {
-        if !self.is_scaled {
-            let mu = (self.mu - self.tuning.mu) / constants::RATIO;
-            let phi = self.phi / constants::RATIO;
-            self.mu = mu;
-            self.phi = phi;
-            self.is_scaled = true;
+    /// The tuning parameters this rating was created with.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::new(1200.0, 200.0, 0.05, 0.6);
+    /// let team_1 = Rating::new(&tuning);
+    /// assert_eq!(team_1.tuning().mu, 1200.0);
+    /// ```
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    /// Checks that every field is a finite number and that `phi` and `sigma` are positive,
+    /// along with the attached tuning parameters.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning, GlickoError};
+    ///
+    /// let tuning = Tuning::default();
+    /// let mut rating = Rating::new(&tuning);
+    /// assert!(rating.validate().is_ok());
+    ///
+    /// rating.phi = 0.0;
+    /// assert_eq!(rating.validate(), Err(GlickoError::NonPositive("phi")));
+    /// ```
+    pub fn validate(&self) -> Result<(), GlickoError> {
+        if !self.mu.is_finite() {
+            return Err(GlickoError::NonFinite("mu"));
         }
+        validate_positive("phi", self.phi)?;
+        validate_positive("sigma", self.sigma)?;
+        self.tuning.validate()
     }
 
-    /// Scales a rating up to the nominal scale
-    pub(crate) fn scale_up(&mut self) {
-        if self.is_scaled {
-            let mu = (self.mu * constants::RATIO) + self.tuning.mu;
-            let phi = self.phi * constants::RATIO;
-            self.mu = mu;
-            self.phi = phi;
-            self.is_scaled = false;
+    /// Converts a rating to the Glicko2 scale
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let scaled = Rating::new(&tuning).to_glicko2();
+    /// assert_eq!(scaled.mu, 0.0);
+    /// ```
+    pub fn to_glicko2(&self) -> Glicko2Rating {
+        Glicko2Rating {
+            mu: (self.mu - self.tuning.mu) / constants::RATIO,
+            phi: self.phi / constants::RATIO,
+            sigma: self.sigma,
+            tuning: self.tuning,
         }
     }
 
-    /// Decay a rating for a team that has not played during a period.
+    /// Decay a rating for a team that has not played during a period. Like
+    /// [`Rating::decay_periods`], the deviation never grows past the tuning's initial deviation.
     /// # Example
     /// ```
     /// use glicko_2::{Rating, Tuning};
-    /// 
+    ///
     /// let tuning = Tuning::default();
     /// let mut new_rating = Rating::new(&tuning);
-    /// 
+    ///
     /// new_rating.decay();
     /// ```
     pub fn decay(&mut self) {
-        if !self.is_scaled {
-            self.scale_down();
+        let mut scaled = self.to_glicko2();
+        scaled.phi = algorithm::decayed_phi(&scaled, 1.0);
+        *self = scaled.to_display();
+    }
+
+    /// Decay a rating for a team that has not played for `periods` rating periods, which may
+    /// be fractional. The deviation grows as `φ² + periods·σ²` in one step, but never past the
+    /// tuning's initial deviation, so inactive teams are never less certain than newcomers.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Rating::try_decay_periods`] would return.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let mut rating = Rating::new(&tuning);
+    /// rating.phi = 50.0;
+    ///
+    /// rating.decay_periods(6.0);
+    /// assert!(rating.phi > 50.0);
+    /// ```
+    pub fn decay_periods(&mut self, periods: f64) {
+        if let Err(error) = self.try_decay_periods(periods) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`Rating::decay_periods`]. The rating is left untouched if an error
+    /// is returned.
+    pub fn try_decay_periods(&mut self, periods: f64) -> Result<(), GlickoError> {
+        validate_non_negative("periods", periods)?;
+        self.validate()?;
+        let mut scaled = self.to_glicko2();
+        scaled.phi = algorithm::decayed_phi(&scaled, periods);
+        *self = scaled.to_display();
+        Ok(())
+    }
+
+    /// Decay a rating for a team that has not played for `elapsed`, where each rating period
+    /// lasts `period_len`. See [`Rating::decay_periods`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Rating::try_decay_for`] would return.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let mut rating = Rating::new(&tuning);
+    /// rating.phi = 50.0;
+    ///
+    /// // Six weeks away with weekly rating periods
+    /// let week = Duration::from_secs(7 * 24 * 60 * 60);
+    /// rating.decay_for(week * 6, week);
+    /// ```
+    pub fn decay_for(&mut self, elapsed: Duration, period_len: Duration) {
+        if let Err(error) = self.try_decay_for(elapsed, period_len) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`Rating::decay_for`]. The rating is left untouched if an error is
+    /// returned.
+    pub fn try_decay_for(
+        &mut self,
+        elapsed: Duration,
+        period_len: Duration,
+    ) -> Result<(), GlickoError> {
+        if period_len.is_zero() {
+            return Err(GlickoError::NonPositive("period_len"));
+        }
+        self.try_decay_periods(elapsed.as_secs_f64() / period_len.as_secs_f64())
+    }
+
+    /// Converts a rating to be updated with another [`Algorithm`]. Every algorithm shares the
+    /// display scale, so the rating and deviation carry over unchanged. Only Glicko-2 updates
+    /// volatility, so converting to another algorithm and back keeps the original volatility.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Algorithm, Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let rating = Rating::new(&tuning);
+    ///
+    /// let legacy = rating.to_algorithm(Algorithm::Glicko1 { c: 34.6 });
+    /// assert_eq!(legacy.mu, rating.mu);
+    /// assert_eq!(legacy.to_algorithm(Algorithm::Glicko2).sigma, rating.sigma);
+    /// ```
+    pub fn to_algorithm(&self, algorithm: Algorithm) -> Rating {
+        Rating {
+            tuning: self.tuning.with_algorithm(algorithm),
+            ..*self
+        }
+    }
+
+    /// The range a team's true rating lies in with the given confidence, such as `0.95` for a
+    /// 95% interval, as `(low, high)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Rating::try_interval`] would return.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let mut rating = Rating::new(&tuning);
+    /// rating.mu = 1300.0;
+    /// rating.phi = 25.0;
+    ///
+    /// let (low, high) = rating.interval(0.95);
+    /// assert_eq!((low.round(), high.round()), (1251.0, 1349.0));
+    /// ```
+    pub fn interval(&self, confidence: f64) -> (f64, f64) {
+        match self.try_interval(confidence) {
+            Ok(interval) => interval,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Fallible version of [`Rating::interval`], which fails if `confidence` is not strictly
+    /// between zero and one.
+    pub fn try_interval(&self, confidence: f64) -> Result<(f64, f64), GlickoError> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(GlickoError::InvalidConfidence(confidence));
+        }
+        let spread = normal_quantile((1.0 + confidence) / 2.0) * self.phi;
+        Ok((self.mu - spread, self.mu + spread))
+    }
+
+    /// A conservative estimate of a team's rating, `mu - k * phi`, where `k` is the number of
+    /// rating deviations to subtract, such as [`constants::CONSERVATIVE_K`](crate::constants).
+    /// Uncertain ratings score lower, so a new team cannot top a leaderboard after a single
+    /// lucky win.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let rating = Rating::new(&tuning);
+    ///
+    /// assert_eq!(rating.conservative_score(2.0), 1500.0 - 2.0 * 350.0);
+    /// ```
+    pub fn conservative_score(&self, k: f64) -> f64 {
+        self.mu - k * self.phi
+    }
+
+    /// Orders ratings for a leaderboard, highest [`Rating::conservative_score`] first, using
+    /// the same `k` for both ratings.
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{constants, Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    ///
+    /// let mut veteran = Rating::new(&tuning);
+    /// veteran.mu = 1700.0;
+    /// veteran.phi = 50.0;
+    /// let mut newcomer = Rating::new(&tuning);
+    /// newcomer.mu = 1850.0;
+    /// newcomer.phi = 300.0;
+    ///
+    /// let mut ladder = vec![("newcomer", newcomer), ("veteran", veteran)];
+    /// ladder.sort_by(|(_, a), (_, b)| a.leaderboard_cmp(b, constants::CONSERVATIVE_K));
+    /// assert_eq!(ladder[0].0, "veteran");
+    /// ```
+    pub fn leaderboard_cmp(&self, other: &Rating, k: f64) -> Ordering {
+        other
+            .conservative_score(k)
+            .total_cmp(&self.conservative_score(k))
+    }
+}
+
+/// A live estimate of a team's rating partway through a rating period, on the display scale.
+///
+/// Provisional ratings are read-only: their fields are private and they cannot be turned back
+/// into a [`Rating`], so they can be shown on a ladder but never fed into another update.
+/// The official update still happens when the period closes.
+///
+/// ```compile_fail
+/// use glicko_2::{Rating, Tuning, game::Outcome};
+///
+/// let tuning = Tuning::default();
+/// let team = Rating::new(&tuning);
+///
+/// let mut live = glicko_2::algorithm::provisional(&team, vec![]);
+/// live.mu = 2000.0;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProvisionalRating {
+    mu: f64,
+    phi: f64,
+    sigma: f64,
+}
+
+impl ProvisionalRating {
+    /// Wrap a rating that must not be used as input to other updates.
+    pub(crate) fn new(rating: &Rating) -> Self {
+        Self {
+            mu: rating.mu,
+            phi: rating.phi,
+            sigma: rating.sigma,
+        }
+    }
+
+    /// The provisional rating.
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// The provisional rating deviation.
+    pub fn phi(&self) -> f64 {
+        self.phi
+    }
+
+    /// The provisional volatility.
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+/// How much a rating would move, on the display scale.
+///
+/// - `mu`: Change in rating, such as `14.0` for a "+14"
+/// - `phi`: Change in rating deviation
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RatingChange {
+    pub mu: f64,
+    pub phi: f64,
+}
+
+impl RatingChange {
+    /// The change from `before` to `after`.
+    pub(crate) fn between(before: &Rating, after: &Rating) -> Self {
+        Self {
+            mu: after.mu - before.mu,
+            phi: after.phi - before.phi,
+        }
+    }
+}
+
+impl Glicko2Rating {
+    /// The tuning parameters this rating was created with.
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    /// Converts a rating back to the nominal display scale
+    ///
+    /// # Example
+    /// ```
+    /// use glicko_2::{Rating, Tuning};
+    ///
+    /// let tuning = Tuning::default();
+    /// let rating = Rating::new(&tuning).to_glicko2().to_display();
+    /// assert_eq!(rating.mu, 1500.0);
+    /// ```
+    pub fn to_display(&self) -> Rating {
+        Rating {
+            mu: (self.mu * constants::RATIO) + self.tuning.mu,
+            phi: self.phi * constants::RATIO,
+            sigma: self.sigma,
+            tuning: self.tuning,
+        }
+    }
+}
+
+impl From<Rating> for Glicko2Rating {
+    fn from(rating: Rating) -> Self {
+        rating.to_glicko2()
+    }
+}
+
+impl From<Glicko2Rating> for Rating {
+    fn from(rating: Glicko2Rating) -> Self {
+        rating.to_display()
+    }
+}
+
+/// Checks that a field is a finite number greater than zero.
+pub(crate) fn validate_positive(field: &'static str, value: f64) -> Result<(), GlickoError> {
+    if !value.is_finite() {
+        Err(GlickoError::NonFinite(field))
+    } else if value <= 0.0 {
+        Err(GlickoError::NonPositive(field))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that a field is a finite number that is zero or greater.
+pub(crate) fn validate_non_negative(field: &'static str, value: f64) -> Result<(), GlickoError> {
+    if !value.is_finite() {
+        Err(GlickoError::NonFinite(field))
+    } else if value < 0.0 {
+        Err(GlickoError::Negative(field))
+    } else {
+        Ok(())
+    }
+}
+
+/// The value below which a standard normal variable falls with probability `p`, using
+/// Acklam's rational approximation, accurate to about `1e-9`.
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    // Both tails share one approximation, mirrored around the median
+    let tail = |p: f64| {
+        let q = (-2.0 * libm::log(p)).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail(p)
+    } else if p > 1.0 - P_LOW {
+        -tail(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Current version of the serialized rating format.
+#[cfg(feature = "serde")]
+const RATING_FORMAT_VERSION: u32 = 1;
+
+/// The stable, versioned layout used to serialize a [`Rating`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RatingRecord {
+    version: u32,
+    mu: f64,
+    phi: f64,
+    sigma: f64,
+    tuning: Tuning,
+}
+
+#[cfg(feature = "serde")]
+impl From<Rating> for RatingRecord {
+    fn from(rating: Rating) -> Self {
+        RatingRecord {
+            version: RATING_FORMAT_VERSION,
+            mu: rating.mu,
+            phi: rating.phi,
+            sigma: rating.sigma,
+            tuning: rating.tuning,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RatingRecord> for Rating {
+    type Error = GlickoError;
+
+    fn try_from(record: RatingRecord) -> Result<Self, Self::Error> {
+        if record.version != RATING_FORMAT_VERSION {
+            return Err(GlickoError::UnsupportedVersion(record.version));
         }
-        let vinculum = self.phi.powi(2) + self.sigma.powi(2);
-        self.phi = vinculum.sqrt();
-        self.scale_up();
+        let rating = Rating {
+            mu: record.mu,
+            phi: record.phi,
+            sigma: record.sigma,
+            tuning: record.tuning,
+        };
+        rating.validate()?;
+        Ok(rating)
     }
 }
@@ -0,0 +1,398 @@
+/*!
+Rebuilds ratings from a timestamped match log by splitting it into rating periods
+*/
+use std::hash::Hash;
+
+use crate::glicko2::{
+    error::GlickoError, game::Outcome, history::RatingHistory, period::validate_game,
+    system::RatingSystem, tuning::Tuning,
+};
+
+/// Seconds in a day, used to split timestamps into calendar periods.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A game from a match log, with `outcome` from the perspective of `player`.
+///
+/// - `timestamp`: When the game was played, in seconds since the Unix epoch (UTC)
+/// - `weight`: How much the game counts, see [`RatingPeriod::add_weighted_game`](crate::RatingPeriod::add_weighted_game)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimedGame<Id> {
+    pub timestamp: i64,
+    pub player: Id,
+    pub opponent: Id,
+    pub outcome: Outcome,
+    pub weight: f64,
+}
+
+impl<Id> TimedGame<Id> {
+    /// Create a regular game played at `timestamp`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{game::Outcome, replay::TimedGame};
+    ///
+    /// let game = TimedGame::new(1_700_000_000, "alice", "bob", Outcome::Win);
+    /// assert_eq!(game.weight, 1.0);
+    /// ```
+    pub fn new(timestamp: i64, player: Id, opponent: Id, outcome: Outcome) -> Self {
+        Self {
+            timestamp,
+            player,
+            opponent,
+            outcome,
+            weight: 1.0,
+        }
+    }
+}
+
+/// A calendar unit used to split a match log into rating periods, in UTC.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Calendar {
+    Day,
+    /// Weeks starting on Monday
+    Week,
+    Month,
+    Year,
+}
+
+/// How a match log is split into rating periods.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeriodPolicy {
+    /// Periods of `length` seconds, counted from the `origin` timestamp
+    Fixed { origin: i64, length: i64 },
+    /// A new period after every given number of games
+    Games(u64),
+    /// Calendar days, weeks, months or years
+    Calendar(Calendar),
+}
+
+impl PeriodPolicy {
+    /// Checks that fixed periods have a positive length and game counts are not zero.
+    pub(crate) fn validate(&self) -> Result<(), GlickoError> {
+        match self {
+            PeriodPolicy::Fixed { length, .. } if *length <= 0 => {
+                Err(GlickoError::NonPositive("policy.length"))
+            }
+            PeriodPolicy::Games(0) => Err(GlickoError::NonPositive("policy.games")),
+            _ => Ok(()),
+        }
+    }
+
+    /// The index of the period containing the game played at `timestamp`, which is the
+    /// `game`th game in the log. Consecutive periods have consecutive indexes.
+    pub(crate) fn period(&self, timestamp: i64, game: u64) -> i64 {
+        match self {
+            PeriodPolicy::Fixed { origin, length } => (timestamp - origin).div_euclid(*length),
+            PeriodPolicy::Games(games) => (game / games) as i64,
+            PeriodPolicy::Calendar(calendar) => {
+                let days = timestamp.div_euclid(SECONDS_PER_DAY);
+                match calendar {
+                    Calendar::Day => days,
+                    // The epoch was a Thursday, so shift weeks to start on Monday
+                    Calendar::Week => (days + 3).div_euclid(7),
+                    Calendar::Month => {
+                        let (year, month) = civil_from_days(days);
+                        year * 12 + month - 1
+                    }
+                    Calendar::Year => civil_from_days(days).0,
+                }
+            }
+        }
+    }
+
+    /// The timestamp a period ends at, which is when the next one starts. Periods split by
+    /// game count have no fixed end, so this returns `None` for them.
+    pub(crate) fn end(&self, period: i64) -> Option<i64> {
+        let next = period + 1;
+        match self {
+            PeriodPolicy::Fixed { origin, length } => Some(origin + next * length),
+            PeriodPolicy::Games(_) => None,
+            PeriodPolicy::Calendar(calendar) => {
+                let days = match calendar {
+                    Calendar::Day => next,
+                    Calendar::Week => next * 7 - 3,
+                    Calendar::Month => {
+                        days_from_civil(next.div_euclid(12), next.rem_euclid(12) + 1)
+                    }
+                    Calendar::Year => days_from_civil(next, 1),
+                };
+                Some(days * SECONDS_PER_DAY)
+            }
+        }
+    }
+}
+
+/// The `(year, month)` of a day counted from the Unix epoch, from Howard Hinnant's
+/// `civil_from_days`, using only integer arithmetic.
+fn civil_from_days(days: i64) -> (i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months counted from March, so the leap day falls at the end of the year
+    let march_month = (5 * day_of_year + 2) / 153;
+    let month = if march_month < 10 {
+        march_month + 3
+    } else {
+        march_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+/// The day counted from the Unix epoch that `month` of `year` starts on, from Howard Hinnant's
+/// `days_from_civil`, using only integer arithmetic.
+fn days_from_civil(year: i64, month: i64) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Replays a match log one game at a time, closing a rating period whenever the policy says
+/// a new one has started. Periods without any games still decay every known player, with
+/// a run of them applied in one step however long the gap between games.
+///
+/// Period boundaries are computed with integer arithmetic only, each player's games are
+/// rated in log order, and `exp` and `ln` come from the pure Rust `libm` rather than the
+/// platform's math library, so replaying the same log with the same tuning always produces
+/// bit-identical ratings on every platform.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Tuning, game::Outcome, replay::{Calendar, PeriodPolicy, Replay, TimedGame}};
+///
+/// let mut replay = Replay::new(Tuning::default(), PeriodPolicy::Calendar(Calendar::Week));
+/// replay.push(TimedGame::new(1_700_000_000, "alice", "bob", Outcome::Win));
+/// replay.push(TimedGame::new(1_700_086_400, "bob", "carol", Outcome::Draw));
+///
+/// let system = replay.finish();
+/// assert!(system.get(&"alice").unwrap().mu > 1500.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Replay<Id> {
+    history: RatingHistory<Id>,
+    record: bool,
+    policy: PeriodPolicy,
+    period: Option<i64>,
+    last_timestamp: Option<i64>,
+    games: u64,
+}
+
+impl<Id> Replay<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    /// Start replaying a log from scratch, rating new players with `tuning`.
+    pub fn new(tuning: Tuning, policy: PeriodPolicy) -> Self {
+        Self::resume(RatingSystem::new(tuning), policy)
+    }
+
+    /// Continue replaying on top of existing ratings, such as the result of an earlier replay.
+    /// Any games already recorded in the system's current period are rated with the first
+    /// period of the replay.
+    pub fn resume(system: RatingSystem<Id>, policy: PeriodPolicy) -> Self {
+        Self {
+            history: RatingHistory::from_system(system),
+            record: false,
+            policy,
+            period: None,
+            last_timestamp: None,
+            games: 0,
+        }
+    }
+
+    /// Continue replaying on top of an existing history, keeping a snapshot of every rating
+    /// as each period closes. See [`Replay::with_history`].
+    pub fn resume_history(history: RatingHistory<Id>, policy: PeriodPolicy) -> Self {
+        Self {
+            history,
+            record: true,
+            policy,
+            period: None,
+            last_timestamp: None,
+            games: 0,
+        }
+    }
+
+    /// Keep a snapshot of every rating as each period closes, to be returned by
+    /// [`Replay::finish_history`]. Snapshots are stamped with the end of their period, or
+    /// with the last game in the period when periods are split by game count. A run of empty
+    /// periods only gets one snapshot, stamped with the end of the last of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, game::Outcome, replay::{PeriodPolicy, Replay, TimedGame}};
+    ///
+    /// let policy = PeriodPolicy::Fixed { origin: 0, length: 100 };
+    /// let mut replay = Replay::new(Tuning::default(), policy).with_history();
+    /// replay.push(TimedGame::new(10, "alice", "bob", Outcome::Win));
+    /// replay.push(TimedGame::new(250, "alice", "bob", Outcome::Loss));
+    ///
+    /// let history = replay.finish_history();
+    /// // Periods closed at 100, 200 (empty) and 300
+    /// assert_eq!(history.snapshots(&"alice").len(), 3);
+    /// assert!(history.rating_at(&"alice", 299).unwrap().mu > 1500.0);
+    /// ```
+    pub fn with_history(mut self) -> Self {
+        self.record = true;
+        self
+    }
+
+    /// The ratings as of the last closed period, along with the games in the current one.
+    pub fn system(&self) -> &RatingSystem<Id> {
+        self.history.system()
+    }
+
+    /// Replay the next game in the log.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Replay::try_push`] would return.
+    pub fn push(&mut self, game: TimedGame<Id>) {
+        if let Err(error) = self.try_push(game) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`Replay::push`], which fails if the game was played before the
+    /// previous one, the policy is invalid, or the game could never be rated. See
+    /// [`RatingSystem::try_add_weighted_game`]. The game is not recorded if an error is
+    /// returned.
+    pub fn try_push(&mut self, game: TimedGame<Id>) -> Result<(), GlickoError> {
+        self.policy.validate()?;
+        if self
+            .last_timestamp
+            .is_some_and(|timestamp| game.timestamp < timestamp)
+        {
+            return Err(GlickoError::OutOfOrder);
+        }
+        validate_game(&game.player, &game.opponent, game.outcome, game.weight)?;
+
+        let period = self.policy.period(game.timestamp, self.games);
+        if let Some(current) = self.period.filter(|current| *current < period) {
+            self.close(current)?;
+            // Every empty period before the game's own is decayed in one step
+            let empty = period - current - 1;
+            if empty > 0 {
+                self.skip(period - 1, empty)?;
+            }
+        }
+        self.period = Some(period);
+        self.last_timestamp = Some(game.timestamp);
+        self.games += 1;
+        self.history
+            .add_weighted_game(game.player, game.opponent, game.outcome, game.weight);
+        Ok(())
+    }
+
+    /// Close `period`, taking snapshots if history is being kept.
+    fn close(&mut self, period: i64) -> Result<(), GlickoError> {
+        if !self.record {
+            return self.history.system_mut().try_close_period();
+        }
+        // Only periods split by game count lack an end, and those are never empty
+        let end = self
+            .policy
+            .end(period)
+            .or(self.last_timestamp)
+            .unwrap_or_default();
+        self.history.try_close_period_at(end)
+    }
+
+    /// Decay everyone through `count` empty periods ending with `last`, taking a single
+    /// snapshot if history is being kept.
+    fn skip(&mut self, last: i64, count: i64) -> Result<(), GlickoError> {
+        let periods = count as f64;
+        if !self.record {
+            return self.history.system_mut().try_decay_periods(periods);
+        }
+        let end = self
+            .policy
+            .end(last)
+            .or(self.last_timestamp)
+            .unwrap_or_default();
+        self.history.try_decay_periods_at(end, periods)
+    }
+
+    /// Close the final period and return the ratings.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Replay::try_finish`] would return.
+    pub fn finish(self) -> RatingSystem<Id> {
+        match self.try_finish() {
+            Ok(system) => system,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Fallible version of [`Replay::finish`].
+    pub fn try_finish(self) -> Result<RatingSystem<Id>, GlickoError> {
+        Ok(self.try_finish_history()?.into_system())
+    }
+
+    /// Close the final period and return the ratings along with their history, which only
+    /// has snapshots if it was kept with [`Replay::with_history`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`Replay::try_finish_history`] would return.
+    pub fn finish_history(self) -> RatingHistory<Id> {
+        match self.try_finish_history() {
+            Ok(history) => history,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Fallible version of [`Replay::finish_history`].
+    pub fn try_finish_history(mut self) -> Result<RatingHistory<Id>, GlickoError> {
+        if let Some(period) = self.period {
+            self.close(period)?;
+        }
+        Ok(self.history)
+    }
+}
+
+/// Rebuild every rating from an ordered match log, such as after changing the tuning.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Tuning, game::Outcome, replay::{self, PeriodPolicy, TimedGame}};
+///
+/// let log = vec![
+///     TimedGame::new(0, "alice", "bob", Outcome::Win),
+///     TimedGame::new(60, "bob", "carol", Outcome::Loss),
+///     TimedGame::new(120, "carol", "alice", Outcome::Draw),
+/// ];
+///
+/// // A new rating period every two games
+/// let system = replay::replay(Tuning::default(), log, PeriodPolicy::Games(2)).unwrap();
+/// assert_eq!(system.len(), 3);
+/// ```
+pub fn replay<Id, I>(
+    tuning: Tuning,
+    games: I,
+    policy: PeriodPolicy,
+) -> Result<RatingSystem<Id>, GlickoError>
+where
+    Id: Eq + Hash + Clone,
+    I: IntoIterator<Item = TimedGame<Id>>,
+{
+    let mut replay = Replay::new(tuning, policy);
+    for game in games {
+        replay.try_push(game)?;
+    }
+    replay.try_finish()
+}
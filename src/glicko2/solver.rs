@@ -0,0 +1,238 @@
+/*!
+Root finders used to determine the new volatility in Step 5 of the algorithm
+*/
+use crate::glicko2::{
+    constants::{EPSILON, MAX_ITERATIONS},
+    error::GlickoError,
+    rating::validate_positive,
+};
+
+/// Root finding method used to solve for the new volatility.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolverMethod {
+    /// The regula falsi variant recommended by the paper
+    Illinois,
+    /// Plain bisection over the same bracket, slower but very robust
+    Bisection,
+    /// Newton's method starting from the current volatility
+    Newton,
+}
+
+/// Configuration for the volatility solver.
+///
+/// - `tolerance`: Stop once the solver's step is at most this large
+/// - `max_iterations`: Give up once the solver has taken this many steps
+/// - `method`: The root finding method to use
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SolverConfig {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub method: SolverMethod,
+}
+
+impl SolverConfig {
+    /// Create a custom solver configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::solver::{SolverConfig, SolverMethod};
+    ///
+    /// let default_solver = SolverConfig::default();
+    /// let custom_solver = SolverConfig::new(1e-9, 50, SolverMethod::Newton);
+    /// ```
+    pub const fn new(tolerance: f64, max_iterations: usize, method: SolverMethod) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+            method,
+        }
+    }
+
+    /// Checks that the tolerance and iteration limit are positive.
+    pub(crate) fn validate(&self) -> Result<(), GlickoError> {
+        validate_positive("tuning.solver.tolerance", self.tolerance)?;
+        if self.max_iterations == 0 {
+            return Err(GlickoError::NonPositive("tuning.solver.max_iterations"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self::new(EPSILON, MAX_ITERATIONS, SolverMethod::Illinois)
+    }
+}
+
+/// Diagnostics describing how the volatility solver behaved.
+///
+/// - `iterations`: Number of steps taken, including any steps spent searching for a bracket
+/// - `converged`: Whether the solver reached the tolerance within the iteration limit
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SolverReport {
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// The optimality criterion f(x) from Step 5 of the paper, where x = ln(sigma'^2).
+pub(crate) struct Criterion {
+    /// a = ln(sigma^2)
+    pub(crate) alpha: f64,
+    /// phi^2 + v
+    pub(crate) spread: f64,
+    /// The squared estimated improvement, difference^2
+    pub(crate) diff_squared: f64,
+    pub(crate) tau: f64,
+}
+
+impl Criterion {
+    fn value(&self, x: f64) -> f64 {
+        let tmp = self.spread + libm::exp(x);
+        let tmp_2 = 2.0 * tmp.powi(2);
+        let a = libm::exp(x) * (self.diff_squared - tmp) / tmp_2;
+        let b = (x - self.alpha) / self.tau.powi(2);
+        a - b
+    }
+
+    fn derivative(&self, x: f64) -> f64 {
+        let e_x = libm::exp(x);
+        let tmp = self.spread + e_x;
+        let numerator = (self.diff_squared - self.spread - 2.0 * e_x) * tmp
+            - 2.0 * e_x * (self.diff_squared - tmp);
+        let a = e_x * numerator / (2.0 * tmp.powi(3));
+        a - 1.0 / self.tau.powi(2)
+    }
+
+    /// Find the root of the criterion, returning x along with a report of how the search went.
+    pub(crate) fn solve(&self, config: &SolverConfig) -> (f64, SolverReport) {
+        match config.method {
+            SolverMethod::Illinois => self.illinois(config),
+            SolverMethod::Bisection => self.bisection(config),
+            SolverMethod::Newton => self.newton(config),
+        }
+    }
+
+    /// Set the initial bracket for the iterative algorithms, counting any search steps.
+    fn bracket(&self, config: &SolverConfig, report: &mut SolverReport) -> Option<(f64, f64)> {
+        let a = self.alpha;
+        if self.diff_squared > self.spread {
+            return Some((a, libm::log(self.diff_squared - self.spread)));
+        }
+        let mut k = 1.0;
+        while self.value(self.alpha - k * self.tau) < 0.0 {
+            if report.iterations >= config.max_iterations {
+                return None;
+            }
+            report.iterations += 1;
+            k += 1.0;
+        }
+        Some((a, self.alpha - k * self.tau))
+    }
+
+    fn illinois(&self, config: &SolverConfig) -> (f64, SolverReport) {
+        let mut report = SolverReport {
+            iterations: 0,
+            converged: false,
+        };
+
+        // 2. Set the initial value for the iterative algorithm
+        let (mut a, mut b) = match self.bracket(config, &mut report) {
+            Some(bracket) => bracket,
+            None => return (self.alpha, report),
+        };
+
+        // 3. Let fA = optimality_criterion(A) and f(B) = optimality_criterion(B)
+        let mut f_a = self.value(a);
+        let mut f_b = self.value(b);
+
+        // 4. While |B-A| > e, carry out the following steps:
+        // (a) Let C = A + (A - B)fA / (fB-fA), and let fC = f(C).
+        // (b) If fCfB < 0, then set A <- B and fA <- fB; otherwise, just set
+        //     fA <- fA/2.
+        // (c) Set B <- C and fB <- fC.
+        // (d) Stop if |B-A| <= e. Repeat the above three steps otherwise.
+        while (b - a).abs() > config.tolerance {
+            // Degenerate input can push the criterion to NaN, which would otherwise never terminate
+            if report.iterations >= config.max_iterations || !(f_a.is_finite() && f_b.is_finite()) {
+                return (a, report);
+            }
+            let c = a + (a - b) * f_a / (f_b - f_a);
+            let f_c = self.value(c);
+            report.iterations += 1;
+            // An exact root never changes sign again, so the bracket would stop shrinking
+            if f_c == 0.0 {
+                report.converged = true;
+                return (c, report);
+            }
+            if f_c * f_b < 0.0 {
+                a = b;
+                f_a = f_b;
+            } else {
+                f_a /= 2.0;
+            }
+            b = c;
+            f_b = f_c;
+        }
+
+        report.converged = a.is_finite();
+        (a, report)
+    }
+
+    fn bisection(&self, config: &SolverConfig) -> (f64, SolverReport) {
+        let mut report = SolverReport {
+            iterations: 0,
+            converged: false,
+        };
+
+        let (mut a, mut b) = match self.bracket(config, &mut report) {
+            Some(bracket) => bracket,
+            None => return (self.alpha, report),
+        };
+        let mut f_a = self.value(a);
+
+        while (b - a).abs() > config.tolerance {
+            if report.iterations >= config.max_iterations || !f_a.is_finite() {
+                return (a, report);
+            }
+            let c = (a + b) / 2.0;
+            let f_c = self.value(c);
+            if f_c * f_a > 0.0 {
+                a = c;
+                f_a = f_c;
+            } else {
+                b = c;
+            }
+            report.iterations += 1;
+        }
+
+        report.converged = a.is_finite();
+        (a, report)
+    }
+
+    fn newton(&self, config: &SolverConfig) -> (f64, SolverReport) {
+        let mut report = SolverReport {
+            iterations: 0,
+            converged: false,
+        };
+
+        let mut x = self.alpha;
+        while report.iterations < config.max_iterations {
+            let step = self.value(x) / self.derivative(x);
+            if !step.is_finite() {
+                return (x, report);
+            }
+            x -= step;
+            report.iterations += 1;
+            if step.abs() <= config.tolerance {
+                report.converged = x.is_finite();
+                break;
+            }
+        }
+
+        (x, report)
+    }
+}
@@ -0,0 +1,229 @@
+/*!
+A registry that owns every player's rating and the current rating period
+*/
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+};
+
+use crate::glicko2::{
+    error::GlickoError,
+    game::Outcome,
+    period::{validate_game, RatingPeriod},
+    rating::{ProvisionalRating, Rating},
+    tuning::Tuning,
+};
+
+/// Every player's rating, keyed by your own player IDs, along with the games recorded during
+/// the current rating period.
+///
+/// Players get a fresh [`Rating::new`] the first time they are seen. Games are only applied
+/// when the period is closed with [`RatingSystem::close_period`], which updates everyone
+/// simultaneously from their pre-period ratings and decays players who did not play.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Tuning, constants, game::Outcome, system::RatingSystem};
+///
+/// let mut system = RatingSystem::new(Tuning::default());
+/// system.add_player("carol");
+/// system.add_game("alice", "bob", Outcome::Win);
+/// system.add_game("bob", "alice", Outcome::Draw);
+///
+/// // Alice and Bob are rated against each other's pre-period ratings, Carol is decayed
+/// system.close_period();
+///
+/// assert!(system.get(&"alice").unwrap().mu > 1500.0);
+/// assert_eq!(system.ranking(constants::CONSERVATIVE_K)[0].0, &"alice");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RatingSystem<Id> {
+    tuning: Tuning,
+    ratings: HashMap<Id, Rating>,
+    period: RatingPeriod<Id>,
+}
+
+impl<Id> RatingSystem<Id>
+where
+    Id: Eq + Hash + Clone,
+{
+    /// Create an empty system whose players are rated with `tuning`.
+    pub fn new(tuning: Tuning) -> Self {
+        Self {
+            tuning,
+            ratings: HashMap::new(),
+            period: RatingPeriod::new(),
+        }
+    }
+
+    /// The tuning parameters new players are rated with.
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    /// Register a player, returning their rating. Players who already exist are unchanged.
+    pub fn add_player(&mut self, id: Id) -> &Rating {
+        let tuning = self.tuning;
+        self.ratings
+            .entry(id)
+            .or_insert_with(|| Rating::new(&tuning))
+    }
+
+    /// Import an existing rating for a player, such as one loaded from storage, replacing
+    /// any rating they already had.
+    pub fn insert(&mut self, id: Id, rating: Rating) {
+        self.ratings.insert(id, rating);
+    }
+
+    /// Record a game between `player` and `opponent`, where `outcome` is from the perspective
+    /// of `player`. Either player is registered if they have not been seen before.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingSystem::try_add_game`] would return.
+    pub fn add_game(&mut self, player: Id, opponent: Id, outcome: Outcome) {
+        self.add_weighted_game(player, opponent, outcome, 1.0);
+    }
+
+    /// Fallible version of [`RatingSystem::add_game`], which fails if a player would play
+    /// themselves or the outcome lies outside `[0, 1]`. Nothing is recorded if an error is
+    /// returned.
+    pub fn try_add_game(
+        &mut self,
+        player: Id,
+        opponent: Id,
+        outcome: Outcome,
+    ) -> Result<(), GlickoError> {
+        self.try_add_weighted_game(player, opponent, outcome, 1.0)
+    }
+
+    /// Record a game that counts `weight` times as much as a regular game.
+    /// See [`RatingPeriod::add_weighted_game`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingSystem::try_add_weighted_game`] would return.
+    pub fn add_weighted_game(&mut self, player: Id, opponent: Id, outcome: Outcome, weight: f64) {
+        if let Err(error) = self.try_add_weighted_game(player, opponent, outcome, weight) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingSystem::add_weighted_game`], which also fails if the weight
+    /// is not a positive finite number. Nothing is recorded if an error is returned.
+    pub fn try_add_weighted_game(
+        &mut self,
+        player: Id,
+        opponent: Id,
+        outcome: Outcome,
+        weight: f64,
+    ) -> Result<(), GlickoError> {
+        // A stored game cannot be removed, so one that can never be rated is refused here
+        validate_game(&player, &opponent, outcome, weight)?;
+        self.add_player(player.clone());
+        self.add_player(opponent.clone());
+        self.period
+            .add_weighted_game(player, opponent, outcome, weight);
+        Ok(())
+    }
+
+    /// The games recorded during the current rating period.
+    pub fn period(&self) -> &RatingPeriod<Id> {
+        &self.period
+    }
+
+    /// Close the current rating period, updating every player and starting a new period.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingSystem::try_close_period`] would return.
+    pub fn close_period(&mut self) {
+        if let Err(error) = self.try_close_period() {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingSystem::close_period`]. If an error is returned, no rating
+    /// is changed and the period's games are kept.
+    pub fn try_close_period(&mut self) -> Result<(), GlickoError> {
+        self.period.try_rate(&mut self.ratings)?;
+        self.period = RatingPeriod::new();
+        Ok(())
+    }
+
+    /// Decay every player as if `periods` rating periods passed without any games, in one
+    /// step. See [`Rating::decay_periods`]. Games recorded in the current period are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any error that [`RatingSystem::try_decay_periods`] would return.
+    pub fn decay_periods(&mut self, periods: f64) {
+        if let Err(error) = self.try_decay_periods(periods) {
+            panic!("{error}");
+        }
+    }
+
+    /// Fallible version of [`RatingSystem::decay_periods`]. If an error is returned, no rating
+    /// is changed.
+    pub fn try_decay_periods(&mut self, periods: f64) -> Result<(), GlickoError> {
+        self.ratings = self
+            .ratings
+            .iter()
+            .map(|(id, rating)| {
+                let mut rating = *rating;
+                rating.try_decay_periods(periods)?;
+                Ok((id.clone(), rating))
+            })
+            .collect::<Result<_, GlickoError>>()?;
+        Ok(())
+    }
+
+    /// Estimate every player's rating from the games recorded so far, without closing the
+    /// period. See [`RatingPeriod::provisional_ratings`].
+    pub fn provisional_ratings(&self) -> Result<HashMap<Id, ProvisionalRating>, GlickoError> {
+        self.period.provisional_ratings(&self.ratings)
+    }
+
+    /// The rating of a player, if they have been registered.
+    pub fn get(&self, id: &Id) -> Option<&Rating> {
+        self.ratings.get(id)
+    }
+
+    /// Whether a player has been registered.
+    pub fn contains(&self, id: &Id) -> bool {
+        self.ratings.contains_key(id)
+    }
+
+    /// Number of registered players.
+    pub fn len(&self) -> usize {
+        self.ratings.len()
+    }
+
+    /// Whether no players have been registered yet.
+    pub fn is_empty(&self) -> bool {
+        self.ratings.is_empty()
+    }
+
+    /// Every player and their rating, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Id, Rating> {
+        self.ratings.iter()
+    }
+
+    /// Every player and their rating, ordered for a leaderboard with
+    /// [`Rating::leaderboard_cmp`] using `k` rating deviations.
+    pub fn ranking(&self, k: f64) -> Vec<(&Id, &Rating)> {
+        let mut ranking: Vec<_> = self.ratings.iter().collect();
+        ranking.sort_by(|(_, a), (_, b)| a.leaderboard_cmp(b, k));
+        ranking
+    }
+}
+
+impl<'a, Id> IntoIterator for &'a RatingSystem<Id> {
+    type Item = (&'a Id, &'a Rating);
+    type IntoIter = hash_map::Iter<'a, Id, Rating>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ratings.iter()
+    }
+}
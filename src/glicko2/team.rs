@@ -0,0 +1,288 @@
+/*!
+Provides functions to rate teams built from individual player ratings
+*/
+use crate::glicko2::{
+    algorithm,
+    error::GlickoError,
+    game::Outcome,
+    rating::{Glicko2Rating, Rating},
+    solver::SolverReport,
+};
+
+/// Rule used to combine member ratings into a single team rating.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Aggregation {
+    /// Team rating is the average of its members' ratings
+    Mean,
+    /// Team rating is the sum of its members' ratings on the Glicko2 scale
+    Sum,
+    /// Team rating is the average of its members' ratings, weighted by playtime
+    Playtime,
+    /// Team rating is the average of its members' ratings, with the deviation of the
+    /// least certain member
+    MaxUncertainty,
+}
+
+/// Combines a team's members into a single composite rating.
+/// Each member is paired with the share of the game they played, which is only used by
+/// [`Aggregation::Playtime`] but must always be a positive finite number.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_composite`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, team::{self, Aggregation}};
+///
+/// let tuning = Tuning::default();
+///
+/// let members = [(Rating::new(&tuning), 1.0), (Rating::new(&tuning), 1.0)];
+///
+/// let composite = team::composite(&members, Aggregation::Mean);
+/// assert_eq!(composite.mu, 1500.0);
+/// ```
+pub fn composite(members: &[(Rating, f64)], aggregation: Aggregation) -> Rating {
+    match try_composite(members, aggregation) {
+        Ok(rating) => rating,
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Fallible version of [`composite`].
+pub fn try_composite(
+    members: &[(Rating, f64)],
+    aggregation: Aggregation,
+) -> Result<Rating, GlickoError> {
+    validate(members)?;
+    let scaled = scale(members);
+    let coefficients = coefficients(members, aggregation);
+    Ok(combine(&scaled, &coefficients, aggregation).to_display())
+}
+
+/// Given a team and a set of outcomes against opposing composite ratings, update each member's
+/// rating. The team's composite is rated as a whole, then the change in μ and φ is split back
+/// to each member in proportion to their share of the team's uncertainty, so less certain
+/// members move further.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_rate`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, game::Outcome, team::{self, Aggregation}};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut members = [(Rating::new(&tuning), 1.0), (Rating::new(&tuning), 1.0)];
+/// let opponents = [(Rating::new(&tuning), 1.0), (Rating::new(&tuning), 1.0)];
+///
+/// let opponent = team::composite(&opponents, Aggregation::Mean);
+/// team::rate(&mut members, vec![(Outcome::Win, opponent)], Aggregation::Mean);
+/// assert!(members[0].0.mu > 1500.0);
+/// ```
+pub fn rate(
+    members: &mut [(Rating, f64)],
+    outcomes: Vec<(Outcome, Rating)>,
+    aggregation: Aggregation,
+) {
+    if let Err(error) = try_rate(members, outcomes, aggregation) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`rate`]. No member is changed if an error is returned.
+/// On success, returns a report describing how the volatility solver behaved for the composite.
+pub fn try_rate(
+    members: &mut [(Rating, f64)],
+    outcomes: Vec<(Outcome, Rating)>,
+    aggregation: Aggregation,
+) -> Result<SolverReport, GlickoError> {
+    let (updated, report) = updated_members(members, outcomes, aggregation)?;
+    for ((rating, _), new_rating) in members.iter_mut().zip(updated) {
+        *rating = new_rating;
+    }
+    Ok(report)
+}
+
+/// Updates the members of two teams that played each other.
+/// If the game was a draw, pass `drawn` as `true`.
+///
+/// # Panics
+///
+/// Panics on any error that [`try_compete`] would return.
+///
+/// # Example
+///
+/// ```
+/// use glicko_2::{Rating, Tuning, team::{self, Aggregation}};
+///
+/// let tuning = Tuning::default();
+///
+/// let mut winners = [(Rating::new(&tuning), 40.0), (Rating::new(&tuning), 25.0)];
+/// let mut losers = [(Rating::new(&tuning), 40.0), (Rating::new(&tuning), 40.0)];
+///
+/// team::compete(&mut winners, &mut losers, false, Aggregation::Playtime);
+/// assert!(winners[0].0.mu > winners[1].0.mu);
+/// ```
+pub fn compete(
+    winners: &mut [(Rating, f64)],
+    losers: &mut [(Rating, f64)],
+    drawn: bool,
+    aggregation: Aggregation,
+) {
+    if let Err(error) = try_compete(winners, losers, drawn, aggregation) {
+        panic!("{error}");
+    }
+}
+
+/// Fallible version of [`compete`]. Neither team is changed if an error is returned.
+pub fn try_compete(
+    winners: &mut [(Rating, f64)],
+    losers: &mut [(Rating, f64)],
+    drawn: bool,
+    aggregation: Aggregation,
+) -> Result<(), GlickoError> {
+    // Both teams are rated against the other's pre-game composite
+    let winner_composite = try_composite(winners, aggregation)?;
+    let loser_composite = try_composite(losers, aggregation)?;
+    let (winner_outcome, loser_outcome) = if drawn {
+        (Outcome::Draw, Outcome::Draw)
+    } else {
+        (Outcome::Win, Outcome::Loss)
+    };
+    let (winners_after, _) = updated_members(
+        winners,
+        vec![(winner_outcome, loser_composite)],
+        aggregation,
+    )?;
+    let (losers_after, _) =
+        updated_members(losers, vec![(loser_outcome, winner_composite)], aggregation)?;
+    for ((rating, _), new_rating) in winners.iter_mut().zip(winners_after) {
+        *rating = new_rating;
+    }
+    for ((rating, _), new_rating) in losers.iter_mut().zip(losers_after) {
+        *rating = new_rating;
+    }
+    Ok(())
+}
+
+/// Checks that a team has members, each with a valid rating and playtime.
+fn validate(members: &[(Rating, f64)]) -> Result<(), GlickoError> {
+    if members.is_empty() {
+        return Err(GlickoError::EmptyTeam);
+    }
+    for (rating, playtime) in members {
+        rating.validate()?;
+        if !(playtime.is_finite() && *playtime > 0.0) {
+            return Err(GlickoError::InvalidWeight(*playtime));
+        }
+    }
+    Ok(())
+}
+
+fn scale(members: &[(Rating, f64)]) -> Vec<Glicko2Rating> {
+    members
+        .iter()
+        .map(|(rating, _)| rating.to_glicko2())
+        .collect()
+}
+
+/// The weight each member's rating carries in the composite.
+fn coefficients(members: &[(Rating, f64)], aggregation: Aggregation) -> Vec<f64> {
+    let size = members.len() as f64;
+    match aggregation {
+        Aggregation::Mean | Aggregation::MaxUncertainty => vec![1.0 / size; members.len()],
+        Aggregation::Sum => vec![1.0; members.len()],
+        Aggregation::Playtime => {
+            let total: f64 = members.iter().map(|(_, playtime)| playtime).sum();
+            members
+                .iter()
+                .map(|(_, playtime)| playtime / total)
+                .collect()
+        }
+    }
+}
+
+/// The total variance the members contribute to the composite, sum(c^2 * phi^2).
+fn spread(scaled: &[Glicko2Rating], coefficients: &[f64]) -> f64 {
+    scaled
+        .iter()
+        .zip(coefficients)
+        .map(|(rating, c)| c.powi(2) * rating.phi.powi(2))
+        .sum()
+}
+
+/// Combine members on the Glicko2 scale, using the first member's tuning.
+fn combine(
+    scaled: &[Glicko2Rating],
+    coefficients: &[f64],
+    aggregation: Aggregation,
+) -> Glicko2Rating {
+    let total: f64 = coefficients.iter().sum();
+    let mut composite = scaled[0];
+    composite.mu = scaled
+        .iter()
+        .zip(coefficients)
+        .map(|(rating, c)| c * rating.mu)
+        .sum();
+    composite.phi = match aggregation {
+        Aggregation::MaxUncertainty => scaled.iter().map(|rating| rating.phi).fold(0.0, f64::max),
+        _ => spread(scaled, coefficients).sqrt(),
+    };
+    composite.sigma = scaled
+        .iter()
+        .zip(coefficients)
+        .map(|(rating, c)| c / total * rating.sigma)
+        .sum();
+    composite
+}
+
+/// Rate a team's composite and split the change back to each member, without writing anything.
+fn updated_members(
+    members: &[(Rating, f64)],
+    outcomes: Vec<(Outcome, Rating)>,
+    aggregation: Aggregation,
+) -> Result<(Vec<Rating>, SolverReport), GlickoError> {
+    validate(members)?;
+    let scaled = scale(members);
+    let coefficients = coefficients(members, aggregation);
+    let before = combine(&scaled, &coefficients, aggregation);
+
+    let mut opponents = outcomes;
+    let mut after = before.to_display();
+    let report = algorithm::try_rate(
+        &mut after,
+        opponents
+            .iter_mut()
+            .map(|(outcome, other_rating)| (*outcome, other_rating))
+            .collect(),
+    )?;
+    let after = after.to_glicko2();
+
+    // Members that contribute more uncertainty to the composite absorb more of the change.
+    // This is the exact split for a linear composite, and keeps a one member team identical
+    // to rating that member directly.
+    let spread = spread(&scaled, &coefficients);
+    let mu_change = after.mu - before.mu;
+    let variance_reduction = 1.0 - after.phi.powi(2) / before.phi.powi(2);
+    let sigma_ratio = after.sigma / before.sigma;
+
+    let mut updated = Vec::with_capacity(members.len());
+    for (rating, c) in scaled.iter().zip(coefficients) {
+        let variance = rating.phi.powi(2);
+        let mut new_rating = *rating;
+        new_rating.mu += mu_change * c * variance / spread;
+        new_rating.phi =
+            (variance - variance_reduction * c.powi(2) * variance.powi(2) / spread).sqrt();
+        new_rating.sigma *= sigma_ratio;
+        let new_rating = new_rating.to_display();
+        new_rating.validate()?;
+        updated.push(new_rating);
+    }
+    Ok((updated, report))
+}
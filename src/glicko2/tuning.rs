@@ -2,52 +2,215 @@
 Tuning parameters used for rating and algorithm calculations
 */
 
-use crate::constants;
+use crate::{
+    constants,
+    glicko2::{
+        elo::KFactor,
+        error::GlickoError,
+        margin::MarginOfVictory,
+        rating::{validate_non_negative, validate_positive},
+        solver::{SolverConfig, SolverMethod},
+    },
+};
+
+/// The rating system used to update ratings. All of them share the same display scale, so a
+/// rating can be moved between them with [`Rating::to_algorithm`](crate::Rating::to_algorithm).
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Algorithm {
+    /// Glicko-2, where each rating's volatility grows its deviation between periods
+    #[default]
+    Glicko2,
+    /// The original Glicko, where the deviation grows by the constant `c` rating points each
+    /// period, up to the tuning's initial deviation. Volatility is left untouched.
+    Glicko1 { c: f64 },
+    /// Elo, where a rating moves by its K-factor times how far the score beat expectations.
+    /// Rating deviation and volatility are ignored and left untouched.
+    Elo { k: KFactor },
+}
 
 /// Container for algorithm tuning parameters. More details available in the readme.
-/// 
+///
 /// - `mu`: Default mean rating
 /// - `phi`: Default confidence interval size
 /// - `sigma`: Default Performance volatility
 /// - `tau`: Default change constraint
-#[derive(Debug)]
+/// - `solver`: Tolerance, iteration limit and method for the volatility solver
+/// - `draw_parameter`: Davidson's ν, how likely draws are between evenly matched teams
+/// - `advantage`: Rating points added to the home or first-move side of a game
+/// - `margin`: How winning margins scale updates, or `None` to ignore margins
+/// - `algorithm`: Whether ratings are updated with Glicko-2, Glicko-1 or Elo
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// Fields added in later versions fall back to their defaults when reading older snapshots
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Tuning {
     pub mu: f64,
     pub phi: f64,
     pub sigma: f64,
     pub tau: f64,
+    pub solver: SolverConfig,
+    pub draw_parameter: f64,
+    pub advantage: f64,
+    pub margin: Option<MarginOfVictory>,
+    pub algorithm: Algorithm,
 }
 
 impl Tuning {
     #[allow(clippy::too_many_arguments)]
     /// Create custom tuning parameters for the Glicko2 algorithm.
     /// The default option uses the values provided by the paper.
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use glicko_2::Tuning;
-    /// 
+    ///
     /// let default_tuning = Tuning::default();
     /// let custom_tuning = Tuning::new(1200.0, 200.0, 0.05, 0.6);
     /// ```
-    pub fn new(mu: f64, phi: f64, sigma: f64, tau: f64) -> Self {
+    pub const fn new(mu: f64, phi: f64, sigma: f64, tau: f64) -> Self {
         Self {
             mu,
             phi,
             sigma,
             tau,
+            solver: SolverConfig::new(
+                constants::EPSILON,
+                constants::MAX_ITERATIONS,
+                SolverMethod::Illinois,
+            ),
+            draw_parameter: constants::DRAW_PARAMETER,
+            advantage: constants::ADVANTAGE,
+            margin: None,
+            algorithm: Algorithm::Glicko2,
+        }
+    }
+
+    /// Use a custom configuration for the volatility solver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, solver::{SolverConfig, SolverMethod}};
+    ///
+    /// let tuning = Tuning::default().with_solver(SolverConfig::new(1e-9, 100, SolverMethod::Newton));
+    /// ```
+    pub fn with_solver(mut self, solver: SolverConfig) -> Self {
+        self.solver = solver;
+        self
+    }
+
+    /// Use a custom draw parameter for [`game::outcome_probabilities`](crate::game::outcome_probabilities).
+    /// Evenly matched teams draw with probability `ν / (2 + ν)`, so `1.0` means a third of
+    /// such games are drawn. Use [`game::fit_draw_parameter`](crate::game::fit_draw_parameter)
+    /// to estimate it from past games.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::Tuning;
+    ///
+    /// let tuning = Tuning::default().with_draw_parameter(1.0);
+    /// ```
+    pub fn with_draw_parameter(mut self, draw_parameter: f64) -> Self {
+        self.draw_parameter = draw_parameter;
+        self
+    }
+
+    /// Use a custom advantage, in rating points, for the home or first-move side of a game.
+    /// It is applied to games rated or predicted with a [`Side`](crate::game::Side), such as
+    /// [`game::compete_sided`](crate::game::compete_sided). Use
+    /// [`game::fit_advantage`](crate::game::fit_advantage) to estimate it from past games.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::Tuning;
+    ///
+    /// let tuning = Tuning::default().with_advantage(35.0);
+    /// ```
+    pub fn with_advantage(mut self, advantage: f64) -> Self {
+        self.advantage = advantage;
+        self
+    }
+
+    /// Opt in to margin of victory aware updates for games rated with a margin, such as
+    /// [`algorithm::rate_margin`](crate::algorithm::rate_margin).
+    /// Without this, margins are ignored and every win counts the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, margin::MarginOfVictory};
+    ///
+    /// let tuning = Tuning::default().with_margin(MarginOfVictory::default());
+    /// ```
+    pub fn with_margin(mut self, margin: MarginOfVictory) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    /// Update ratings with another algorithm, such as Glicko-1 for legacy leagues.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Algorithm, Tuning};
+    ///
+    /// let tuning = Tuning::default().with_algorithm(Algorithm::Glicko1 { c: 34.6 });
+    /// ```
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Checks that `mu` and the advantage are finite, that `phi`, `sigma` and `tau` are
+    /// positive finite numbers, that the draw parameter is not negative, and that any margin
+    /// of victory and algorithm configuration is valid.
+    /// Errors name the field by its path from the tuning, such as `tuning.margin.link`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glicko_2::{Tuning, GlickoError};
+    ///
+    /// assert!(Tuning::default().validate().is_ok());
+    /// assert_eq!(
+    ///     Tuning::new(1500.0, 350.0, 0.06, -1.0).validate(),
+    ///     Err(GlickoError::NonPositive("tuning.tau"))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), GlickoError> {
+        if !self.mu.is_finite() {
+            return Err(GlickoError::NonFinite("tuning.mu"));
         }
+        validate_positive("tuning.phi", self.phi)?;
+        validate_positive("tuning.sigma", self.sigma)?;
+        validate_positive("tuning.tau", self.tau)?;
+        validate_non_negative("tuning.draw_parameter", self.draw_parameter)?;
+        if !self.advantage.is_finite() {
+            return Err(GlickoError::NonFinite("tuning.advantage"));
+        }
+        if let Some(margin) = &self.margin {
+            margin.validate()?;
+        }
+        match self.algorithm {
+            Algorithm::Glicko2 => {}
+            Algorithm::Glicko1 { c } => validate_non_negative("tuning.algorithm.c", c)?,
+            Algorithm::Elo { k } => k.validate()?,
+        }
+        self.solver.validate()
     }
 }
 
 impl Default for Tuning {
     fn default() -> Self {
-        Self {
-            mu: constants::MU,
-            phi: constants::PHI,
-            sigma: constants::SIGMA,
-            tau: constants::TAU,
-        }
+        Self::new(
+            constants::MU,
+            constants::PHI,
+            constants::SIGMA,
+            constants::TAU,
+        )
     }
 }
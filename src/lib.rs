@@ -3,7 +3,27 @@
 // Expose the module
 pub mod glicko2;
 // Re-export so we can use these without reaching into the crate
-pub use crate::glicko2::{algorithm, constants, game, rating::Rating, tuning::Tuning};
+pub use crate::glicko2::{
+    algorithm, constants, elo,
+    error::GlickoError,
+    game, history, margin, multiplayer, period,
+    period::RatingPeriod,
+    rating::{DisplayRating, Glicko2Rating, ProvisionalRating, Rating, RatingChange},
+    replay, solver, system,
+    system::RatingSystem,
+    team,
+    tuning::{Algorithm, Tuning},
+};
+
+/// A rating with the given mean and deviation, and the tuning's initial volatility
+#[cfg(test)]
+fn test_rating(tuning: &Tuning, mu: f64, phi: f64) -> Rating {
+    Rating {
+        mu,
+        phi,
+        ..Rating::new(tuning)
+    }
+}
 
 #[cfg(test)]
 mod game_tests {
@@ -14,12 +34,7 @@ mod game_tests {
         tuning::Tuning,
     };
 
-    const TUNING: Tuning = Tuning {
-        mu: MU,
-        phi: PHI,
-        sigma: SIGMA,
-        tau: TAU,
-    };
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
 
     #[test]
     fn win() {
@@ -45,6 +60,16 @@ mod game_tests {
         assert_eq!(loss_val, 0.0)
     }
 
+    #[test]
+    fn score() {
+        let score = game::Outcome::Score(0.625);
+        assert_eq!(score.val(), 0.625);
+        assert_eq!(score.reverse(), game::Outcome::Score(0.375));
+        assert!(score.is_valid());
+        assert!(!game::Outcome::Score(-0.1).is_valid());
+        assert!(!game::Outcome::Score(f64::NAN).is_valid());
+    }
+
     #[test]
     fn constants() {
         assert_eq!(MU, 1500.0);
@@ -62,21 +87,18 @@ mod game_tests {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
         game::compete(&mut new_rating, &mut other_rating, false);
         println!("New: {:?}", new_rating);
-        assert_eq!(new_rating.mu, 1643.2419919603035);
-        assert_eq!(new_rating.phi, 297.73966575502345);
-        assert_eq!(new_rating.sigma, 0.005999997552929708);
-        assert!(!new_rating.is_scaled);
+        assert_eq!(new_rating.mu, 1652.7846700817397);
+        assert_eq!(new_rating.phi, 267.4336913049956);
+        assert_eq!(new_rating.sigma, 0.005999994927968827);
 
         println!("Other: {:?}", other_rating);
-        assert_eq!(other_rating.mu, 1476.3886820234704);
-        assert_eq!(other_rating.phi, 188.4375670743142);
-        assert_eq!(other_rating.sigma, 0.0058999957800978135);
-        assert!(!other_rating.is_scaled);
+        assert_eq!(other_rating.mu, 1389.2862328224694);
+        assert_eq!(other_rating.phi, 186.7491483610482);
+        assert_eq!(other_rating.sigma, 0.005899997643954933);
     }
 
     #[test]
@@ -86,23 +108,20 @@ mod game_tests {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
 
         game::compete(&mut new_rating, &mut other_rating, true);
 
         println!("New: {:?}", new_rating);
-        assert_eq!(new_rating.mu, 1486.1104422036067);
-        assert_eq!(new_rating.phi, 297.73966575383554);
-        assert_eq!(new_rating.sigma, 0.0059999938227804145);
-        assert!(!new_rating.is_scaled);
+        assert_eq!(new_rating.mu, 1478.9865011424515);
+        assert_eq!(new_rating.phi, 267.43369130402556);
+        assert_eq!(new_rating.sigma, 0.00599999072502925);
 
         println!("Other: {:?}", other_rating);
-        assert_eq!(other_rating.mu, 1502.4424914475542);
-        assert_eq!(other_rating.phi, 187.01936485359374);
-        assert_eq!(other_rating.sigma, 0.005899991810567799);
-        assert!(!other_rating.is_scaled);
+        assert_eq!(other_rating.mu, 1456.4467635605806);
+        assert_eq!(other_rating.phi, 186.74914835672553);
+        assert_eq!(other_rating.sigma, 0.005899991679410399);
     }
 
     #[test]
@@ -115,37 +134,91 @@ mod game_tests {
         game::compete(&mut rating_1, &mut rating_2, false);
 
         // Get odds (percent chance team_1 beats team_2)
-        let odds = game::odds(&mut rating_1, &mut rating_2);
+        let odds = game::odds(&rating_1, &rating_2);
         println!("{:?}", odds);
-        assert_eq!(odds, 0.7086345168430092);
+        assert_eq!(odds, 0.7571668628019588);
+    }
+
+    #[test]
+    fn odds_does_not_modify() {
+        let rating_1 = Rating::new(&TUNING);
+        let rating_2 = Rating {
+            mu: 1450.0,
+            phi: 200.0,
+            sigma: 0.0059,
+            tuning: TUNING,
+        };
+        let odds = game::odds(&rating_1, &rating_2);
+        assert!(odds > 0.5);
+        assert_eq!(odds + game::odds(&rating_2, &rating_1), 1.0);
+        assert_eq!(rating_2.mu, 1450.0);
+        assert_eq!(rating_2.phi, 200.0);
+    }
+
+    #[test]
+    fn batch() {
+        let rating = Rating::new(&TUNING);
+        let opponents = [
+            Rating {
+                mu: 1450.0,
+                phi: 200.0,
+                sigma: 0.0059,
+                tuning: TUNING,
+            },
+            Rating::new(&TUNING),
+        ];
+        assert_eq!(
+            game::odds_batch(&rating, &opponents),
+            vec![
+                game::odds(&rating, &opponents[0]),
+                game::odds(&rating, &opponents[1])
+            ]
+        );
+        assert_eq!(
+            game::quality_batch(&rating, &opponents),
+            vec![0.9116055444116669, 1.0]
+        );
+        assert!(game::odds_batch(&rating, &[]).is_empty());
+    }
+
+    #[test]
+    fn shared_leaderboard() {
+        let leaderboard = std::sync::Arc::new(vec![Rating::new(&TUNING); 4]);
+        let handles: Vec<_> = (0..4)
+            .map(|index| {
+                let leaderboard = leaderboard.clone();
+                std::thread::spawn(move || game::odds_batch(&leaderboard[index], &leaderboard))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), vec![0.5; 4]);
+        }
     }
 
     #[test]
     fn quality_team_1_advantage() {
-        let mut new_rating = Rating::new(&TUNING);
-        let mut other_rating = Rating {
+        let new_rating = Rating::new(&TUNING);
+        let other_rating = Rating {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
-        let quality = game::quality(&mut new_rating, &mut other_rating);
+        let quality = game::quality(&new_rating, &other_rating);
         println!("{:?}", quality);
         assert_eq!(quality, 0.9116055444116669);
     }
 
     #[test]
     fn quality_team_2_advantage() {
-        let mut new_rating = Rating::new(&TUNING);
-        let mut other_rating = Rating {
+        let new_rating = Rating::new(&TUNING);
+        let other_rating = Rating {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
-        let quality = game::quality(&mut other_rating, &mut new_rating);
+        let quality = game::quality(&other_rating, &new_rating);
         println!("{:?}", quality);
         assert_eq!(quality, 0.9116055444116669);
     }
@@ -153,18 +226,16 @@ mod game_tests {
 
 #[cfg(test)]
 mod rating_tests {
+    use std::time::Duration;
+
     use crate::glicko2::{
         constants::{MU, PHI, SIGMA, TAU},
-        rating::Rating,
+        error::GlickoError,
+        rating::{DisplayRating, Glicko2Rating, Rating},
         tuning::Tuning,
     };
 
-    const TUNING: Tuning = Tuning {
-        mu: MU,
-        phi: PHI,
-        sigma: SIGMA,
-        tau: TAU,
-    };
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
 
     #[test]
     fn create_rating() {
@@ -173,53 +244,184 @@ mod rating_tests {
         assert_eq!(new_rating.mu, 1500.0);
         assert_eq!(new_rating.phi, 350.0);
         assert_eq!(new_rating.sigma, 0.006);
-        assert!(!new_rating.is_scaled);
     }
 
     #[test]
-    fn scale_down() {
-        let mut new_rating = Rating::new(&TUNING);
-        new_rating.scale_down();
+    fn to_glicko2() {
+        let new_rating = Rating::new(&TUNING).to_glicko2();
         println!("{:?}", new_rating);
         assert_eq!(new_rating.mu, 0.0);
         assert_eq!(new_rating.phi, 2.014761872416068);
         assert_eq!(new_rating.sigma, 0.006);
-        assert!(new_rating.is_scaled);
     }
 
     #[test]
-    fn scale_down_already_down() {
-        let mut new_rating = Rating::new(&TUNING);
-        new_rating.scale_down();
-        new_rating.scale_down();
+    fn glicko2_from_display() {
+        let new_rating = Glicko2Rating::from(Rating::new(&TUNING));
         println!("{:?}", new_rating);
         assert_eq!(new_rating.mu, 0.0);
         assert_eq!(new_rating.phi, 2.014761872416068);
         assert_eq!(new_rating.sigma, 0.006);
-        assert!(new_rating.is_scaled);
     }
 
     #[test]
-    fn scale_up() {
-        let mut new_rating = Rating::new(&TUNING);
-        new_rating.scale_down();
-        new_rating.scale_up();
+    fn to_display() {
+        let new_rating = Rating::new(&TUNING).to_glicko2().to_display();
         println!("{:?}", new_rating);
         assert_eq!(new_rating.mu, 1500.0);
         assert_eq!(new_rating.phi, 350.0);
         assert_eq!(new_rating.sigma, 0.006);
-        assert!(!new_rating.is_scaled);
     }
 
     #[test]
-    fn scale_up_already_up() {
-        let mut new_rating = Rating::new(&TUNING);
-        new_rating.scale_up();
+    fn outlives_tuning() {
+        let rating = {
+            let tuning = Tuning::new(1200.0, 200.0, 0.05, 0.6);
+            Rating::new(&tuning)
+        };
+        assert_eq!(rating.mu, 1200.0);
+        assert_eq!(rating.tuning().tau, 0.6);
+    }
+
+    #[test]
+    fn send_across_threads() {
+        let mut ratings = std::collections::HashMap::new();
+        let mut rating = Rating::new(&TUNING);
+        rating.phi = 200.0;
+        ratings.insert("team_1", rating);
+        let handle = std::thread::spawn(move || {
+            let mut rating = ratings["team_1"];
+            rating.decay();
+            rating
+        });
+        let rating = handle.join().unwrap();
+        assert!(rating.phi > 200.0);
+        assert_eq!(rating.tuning(), &TUNING);
+    }
+
+    #[test]
+    fn display_from_glicko2() {
+        let new_rating = DisplayRating::from(Glicko2Rating {
+            mu: 0.0,
+            phi: 2.0,
+            sigma: 0.006,
+            tuning: TUNING,
+        });
         println!("{:?}", new_rating);
         assert_eq!(new_rating.mu, 1500.0);
-        assert_eq!(new_rating.phi, 350.0);
+        assert_eq!(new_rating.phi, 347.4356);
         assert_eq!(new_rating.sigma, 0.006);
-        assert!(!new_rating.is_scaled);
+    }
+
+    #[test]
+    fn decay_periods_matches_repeated_decay() {
+        let mut decayed = Rating::new(&TUNING);
+        decayed.phi = 50.0;
+        decayed.sigma = 0.06;
+        let mut repeated = decayed;
+        decayed.decay_periods(3.0);
+        for _ in 0..3 {
+            repeated.decay();
+        }
+        assert!((decayed.phi - repeated.phi).abs() < 1e-9);
+        assert_eq!(decayed.mu, repeated.mu);
+    }
+
+    #[test]
+    fn decay_periods_capped() {
+        let mut rating = Rating::new(&TUNING);
+        rating.phi = 340.0;
+        rating.sigma = 0.5;
+        rating.decay_periods(100.0);
+        assert!((rating.phi - PHI).abs() < 1e-9);
+
+        // Ratings already past the cap are left where they are
+        rating.phi = 400.0;
+        rating.decay_periods(1.0);
+        assert!((rating.phi - 400.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decay_for_elapsed_time() {
+        let week = Duration::from_secs(7 * 24 * 60 * 60);
+        let mut by_time = Rating::new(&TUNING);
+        by_time.phi = 60.0;
+        by_time.sigma = 0.06;
+        let mut by_periods = by_time;
+        by_time.decay_for(week * 3 + week / 2, week);
+        by_periods.decay_periods(3.5);
+        assert!((by_time.phi - by_periods.phi).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_decay() {
+        let mut rating = Rating::new(&TUNING);
+        assert_eq!(
+            rating.try_decay_periods(-1.0),
+            Err(GlickoError::Negative("periods"))
+        );
+        assert_eq!(
+            rating.try_decay_for(Duration::from_secs(60), Duration::ZERO),
+            Err(GlickoError::NonPositive("period_len"))
+        );
+        assert_eq!(rating.phi, PHI);
+    }
+
+    #[test]
+    fn interval() {
+        let rating = Rating::new(&TUNING);
+        let (low, high) = rating.interval(0.95);
+        assert!((high - MU - 1.959964 * PHI).abs() < 1e-3);
+        assert!((MU - low - 1.959964 * PHI).abs() < 1e-3);
+
+        let (low, high) = rating.interval(0.6827);
+        assert!((high - low - 2.0 * PHI).abs() < 0.1);
+
+        // Wider intervals for higher confidence
+        assert!(rating.interval(0.99).1 > rating.interval(0.5).1);
+    }
+
+    #[test]
+    fn invalid_interval() {
+        let rating = Rating::new(&TUNING);
+        assert_eq!(
+            rating.try_interval(1.0),
+            Err(GlickoError::InvalidConfidence(1.0))
+        );
+        assert!(rating.try_interval(0.0).is_err());
+        assert!(rating.try_interval(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn conservative_leaderboard() {
+        let mut lucky = Rating::new(&TUNING);
+        lucky.mu = 1662.0;
+        lucky.phi = 290.0;
+        let mut veteran = Rating::new(&TUNING);
+        veteran.mu = 1600.0;
+        veteran.phi = 40.0;
+        assert_eq!(veteran.conservative_score(3.0), 1480.0);
+
+        let mut ladder = [Rating::new(&TUNING), lucky, veteran];
+        ladder.sort_by(|a, b| a.leaderboard_cmp(b, 3.0));
+        assert_eq!(ladder[0].mu, 1600.0);
+        assert_eq!(ladder[1].mu, 1662.0);
+        assert_eq!(ladder[2].mu, MU);
+    }
+
+    #[test]
+    fn leaderboard_without_uncertainty() {
+        let mut lucky = Rating::new(&TUNING);
+        lucky.mu = 1662.0;
+        lucky.phi = 290.0;
+        let mut veteran = Rating::new(&TUNING);
+        veteran.mu = 1600.0;
+        veteran.phi = 40.0;
+
+        // With `k` of zero only the rating itself counts
+        let mut ladder = [veteran, lucky];
+        ladder.sort_by(|a, b| a.leaderboard_cmp(b, 0.0));
+        assert_eq!(ladder[0].mu, 1662.0);
     }
 }
 
@@ -233,42 +435,19 @@ mod algorithm_tests {
         tuning::Tuning,
     };
 
-    const TUNING: Tuning = Tuning {
-        mu: MU,
-        phi: PHI,
-        sigma: SIGMA,
-        tau: TAU,
-    };
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
 
     #[test]
     fn reduce_impact() {
-        let mut new_rating = Rating::new(&TUNING);
-        let mut other_rating = Rating {
-            mu: 1450.0,
-            phi: 200.0,
-            sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
-        };
-        new_rating.scale_down();
-        other_rating.scale_down();
-        let impact = algorithm::reduce_impact(&new_rating, &other_rating);
-        assert_eq!(impact, 0.6158349285183401);
-    }
-
-    #[test]
-    #[should_panic]
-    fn reduce_impact_unscaled() {
         let new_rating = Rating::new(&TUNING);
         let other_rating = Rating {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
-        let impact = algorithm::reduce_impact(&new_rating, &other_rating);
-        assert_eq!(impact, 0.0);
+        let impact = algorithm::reduce_impact(&new_rating.to_glicko2(), &other_rating.to_glicko2());
+        assert_eq!(impact, 0.6158349285183401);
     }
 
     #[test]
@@ -278,18 +457,93 @@ mod algorithm_tests {
             mu: 1450.0,
             phi: 200.0,
             sigma: 0.0059,
-            is_scaled: false,
-            tuning: &TUNING,
+            tuning: TUNING,
         };
         algorithm::rate(
             &mut new_rating,
             vec![(game::Outcome::Win, &mut other_rating)],
         );
         println!("{:?}", new_rating);
-        assert_eq!(new_rating.mu, 1643.2419919603035);
-        assert_eq!(new_rating.phi, 297.73966575502345);
-        assert_eq!(new_rating.sigma, 0.005999997552929708);
-        assert!(!new_rating.is_scaled);
+        assert_eq!(new_rating.mu, 1652.7846700817397);
+        assert_eq!(new_rating.phi, 267.4336913049956);
+        assert_eq!(new_rating.sigma, 0.005999994927968827);
+    }
+
+    #[test]
+    fn score_matches_fixed_outcomes() {
+        let mut from_score = Rating::new(&TUNING);
+        let mut from_draw = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        algorithm::rate(
+            &mut from_score,
+            vec![(game::Outcome::Score(0.5), &mut other_rating)],
+        );
+        algorithm::rate(
+            &mut from_draw,
+            vec![(game::Outcome::Draw, &mut other_rating)],
+        );
+        assert_eq!(from_score.mu, from_draw.mu);
+        assert_eq!(from_score.phi, from_draw.phi);
+    }
+
+    #[test]
+    fn partial_score() {
+        let mut new_rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        algorithm::rate(
+            &mut new_rating,
+            vec![(game::Outcome::Score(0.625), &mut other_rating)],
+        );
+        assert!(new_rating.mu > 1500.0);
+        let mut win_rating = Rating::new(&TUNING);
+        algorithm::rate(
+            &mut win_rating,
+            vec![(game::Outcome::Win, &mut other_rating)],
+        );
+        assert!(new_rating.mu < win_rating.mu);
+    }
+
+    #[test]
+    #[should_panic]
+    fn score_out_of_range() {
+        let mut new_rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        algorithm::rate(
+            &mut new_rating,
+            vec![(game::Outcome::Score(1.5), &mut other_rating)],
+        );
+    }
+
+    #[test]
+    fn weight_matches_repeated_games() {
+        let mut weighted = Rating::new(&TUNING);
+        let mut repeated = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        let mut other_rating_2 = Rating::new(&TUNING);
+        algorithm::rate_weighted(
+            &mut weighted,
+            vec![(game::Outcome::Win, 2.0, &mut other_rating)],
+        );
+        algorithm::rate(
+            &mut repeated,
+            vec![
+                (game::Outcome::Win, &mut other_rating),
+                (game::Outcome::Win, &mut other_rating_2),
+            ],
+        );
+        assert!((weighted.mu - repeated.mu).abs() < 1e-9);
+        assert!((weighted.phi - repeated.phi).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_weight() {
+        let mut new_rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        algorithm::rate_weighted(
+            &mut new_rating,
+            vec![(game::Outcome::Win, 0.0, &mut other_rating)],
+        );
     }
 }
 
@@ -318,3 +572,2109 @@ mod tuning_tests {
         assert_eq!(tuning.tau, 0.6);
     }
 }
+
+#[cfg(test)]
+mod period_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::{self, Outcome},
+        period::RatingPeriod,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn matches_compete() {
+        let mut rating_1 = Rating::new(&TUNING);
+        let mut rating_2 = Rating {
+            mu: 1450.0,
+            phi: 200.0,
+            sigma: 0.0059,
+            tuning: TUNING,
+        };
+
+        let mut ratings = HashMap::new();
+        ratings.insert(1, rating_1);
+        ratings.insert(2, rating_2);
+
+        let mut period = RatingPeriod::new();
+        period.add_game(1, 2, Outcome::Win);
+        period.rate(&mut ratings);
+
+        game::compete(&mut rating_1, &mut rating_2, false);
+        assert_eq!(ratings[&1].mu, rating_1.mu);
+        assert_eq!(ratings[&1].phi, rating_1.phi);
+        assert_eq!(ratings[&2].mu, rating_2.mu);
+        assert_eq!(ratings[&2].phi, rating_2.phi);
+    }
+
+    #[test]
+    fn order_independent() {
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+        ratings.insert("b", Rating::new(&TUNING));
+        ratings.insert("c", Rating::new(&TUNING));
+        let mut reversed = ratings.clone();
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "b", Outcome::Win);
+        period.add_game("b", "c", Outcome::Win);
+        period.rate(&mut ratings);
+
+        let mut other_period = RatingPeriod::new();
+        other_period.add_game("c", "b", Outcome::Loss);
+        other_period.add_game("b", "a", Outcome::Loss);
+        other_period.rate(&mut reversed);
+
+        for id in ["a", "b", "c"] {
+            assert_eq!(ratings[id].mu, reversed[id].mu);
+            assert_eq!(ratings[id].phi, reversed[id].phi);
+            assert_eq!(ratings[id].sigma, reversed[id].sigma);
+        }
+        // b won once and lost once against equal opponents
+        assert!((ratings["b"].mu - MU).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decays_absent_players() {
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+        ratings.insert("b", Rating::new(&TUNING));
+        ratings.insert("idle", Rating::new(&TUNING));
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "b", Outcome::Draw);
+        period.rate(&mut ratings);
+
+        let mut expected = Rating::new(&TUNING);
+        expected.decay();
+        assert_eq!(ratings["idle"].mu, expected.mu);
+        assert_eq!(ratings["idle"].phi, expected.phi);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_player() {
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "missing", Outcome::Win);
+        period.rate(&mut ratings);
+    }
+
+    #[test]
+    fn idle_decay_capped() {
+        let mut returning = Rating::new(&TUNING);
+        returning.phi = 349.9995;
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+        ratings.insert("b", Rating::new(&TUNING));
+        ratings.insert("idle", returning);
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "b", Outcome::Win);
+        period.rate(&mut ratings);
+
+        let mut expected = returning;
+        expected.decay_periods(1.0);
+        assert_eq!(ratings["idle"].phi, expected.phi);
+        assert!((ratings["idle"].phi - PHI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn playing_themselves() {
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+        ratings.insert("b", Rating::new(&TUNING));
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "b", Outcome::Win);
+        period.add_game("a", "a", Outcome::Win);
+        assert_eq!(
+            period.try_rate(&mut ratings),
+            Err(GlickoError::DuplicatePlayer)
+        );
+        assert_eq!(ratings["a"].phi, PHI);
+    }
+}
+
+/// Reproduces the worked example from Glickman's paper, <http://www.glicko.net/glicko/glicko2.pdf>
+#[cfg(test)]
+mod provisional_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        algorithm,
+        constants::{MU, PHI, SIGMA, TAU},
+        game::Outcome,
+        period::RatingPeriod,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn matches_rate() {
+        let team = Rating::new(&TUNING);
+        let mut opponent = Rating::new(&TUNING);
+        opponent.mu = 1600.0;
+
+        let live = algorithm::provisional(&team, vec![(Outcome::Win, &opponent)]);
+        let mut rated = team;
+        algorithm::rate(&mut rated, vec![(Outcome::Win, &mut opponent)]);
+        assert_eq!(live.mu(), rated.mu);
+        assert_eq!(live.phi(), rated.phi);
+        assert_eq!(live.sigma(), rated.sigma);
+        assert_eq!(team.mu, MU);
+    }
+
+    #[test]
+    fn no_games_yet() {
+        let team = Rating::new(&TUNING);
+        let live = algorithm::provisional(&team, vec![]);
+        assert_eq!(live.mu(), MU);
+        assert_eq!(live.phi(), PHI);
+    }
+
+    #[test]
+    fn period_estimates() {
+        let mut carol = Rating::new(&TUNING);
+        carol.phi = 200.0;
+        let mut ratings = HashMap::new();
+        ratings.insert("alice", Rating::new(&TUNING));
+        ratings.insert("bob", Rating::new(&TUNING));
+        ratings.insert("carol", carol);
+
+        let mut period = RatingPeriod::new();
+        period.add_game("alice", "bob", Outcome::Win);
+        period.add_game("bob", "alice", Outcome::Draw);
+
+        let live = period.provisional_ratings(&ratings).unwrap();
+        assert_eq!(ratings["alice"].mu, MU);
+        // Idle players are only decayed when the period closes
+        assert_eq!(live["carol"].phi(), 200.0);
+
+        period.rate(&mut ratings);
+        assert_eq!(live["alice"].mu(), ratings["alice"].mu);
+        assert_eq!(live["bob"].phi(), ratings["bob"].phi);
+        assert!(ratings["carol"].phi > 200.0);
+    }
+}
+
+#[cfg(test)]
+mod preview_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        game::{self, Outcome},
+        period::RatingPeriod,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn matches_compete() {
+        let rating_1 = Rating::new(&TUNING);
+        let mut rating_2 = Rating::new(&TUNING);
+        rating_2.mu = 1700.0;
+        rating_2.phi = 80.0;
+
+        let preview = game::preview(&rating_1, &rating_2);
+
+        let mut winner = rating_1;
+        let mut loser = rating_2;
+        game::compete(&mut winner, &mut loser, false);
+        assert_eq!(preview.win.first.mu, winner.mu - rating_1.mu);
+        assert_eq!(preview.win.second.phi, loser.phi - rating_2.phi);
+
+        let mut winner = rating_2;
+        let mut loser = rating_1;
+        game::compete(&mut winner, &mut loser, false);
+        assert_eq!(preview.loss.first.mu, loser.mu - rating_1.mu);
+        assert_eq!(preview.loss.second.mu, winner.mu - rating_2.mu);
+
+        // The underdog gains from a draw, the favorite loses
+        assert!(preview.draw.first.mu > 0.0);
+        assert!(preview.draw.second.mu < 0.0);
+        assert_eq!(rating_1.mu, MU);
+    }
+
+    #[test]
+    fn period_matches_rate() {
+        let mut carol = Rating::new(&TUNING);
+        carol.phi = 200.0;
+        let mut ratings = HashMap::new();
+        ratings.insert("alice", Rating::new(&TUNING));
+        ratings.insert("bob", Rating::new(&TUNING));
+        ratings.insert("carol", carol);
+
+        let mut period = RatingPeriod::new();
+        period.add_game("alice", "bob", Outcome::Win);
+        period.add_game("bob", "alice", Outcome::Draw);
+
+        let changes = period.preview(&ratings).unwrap();
+        let before = ratings.clone();
+        period.rate(&mut ratings);
+        for (id, change) in &changes {
+            assert_eq!(change.mu, ratings[id].mu - before[id].mu);
+            assert_eq!(change.phi, ratings[id].phi - before[id].phi);
+        }
+        assert_eq!(changes["carol"].mu, 0.0);
+        assert!(changes["carol"].phi > 0.0);
+    }
+
+    #[test]
+    fn period_unknown_player() {
+        let ratings = HashMap::from([("alice", Rating::new(&TUNING))]);
+        let mut period = RatingPeriod::new();
+        period.add_game("alice", "bob", Outcome::Win);
+        assert!(period.preview(&ratings).is_err());
+    }
+}
+
+#[cfg(test)]
+mod conformance_tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        glicko2::{
+            algorithm,
+            game::{self, Outcome},
+            period::RatingPeriod,
+            tuning::Tuning,
+        },
+        test_rating,
+    };
+
+    /// The paper uses tau = 0.5 and a base volatility of 0.06
+    const TUNING: Tuning = Tuning::new(1500.0, 350.0, 0.06, 0.5);
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn paper_example_rate() {
+        let mut rating = test_rating(&TUNING, 1500.0, 200.0);
+        let mut opponent_1 = test_rating(&TUNING, 1400.0, 30.0);
+        let mut opponent_2 = test_rating(&TUNING, 1550.0, 100.0);
+        let mut opponent_3 = test_rating(&TUNING, 1700.0, 300.0);
+
+        algorithm::rate(
+            &mut rating,
+            vec![
+                (Outcome::Win, &mut opponent_1),
+                (Outcome::Loss, &mut opponent_2),
+                (Outcome::Loss, &mut opponent_3),
+            ],
+        );
+
+        // The paper rounds intermediate values, so match it to the precision it publishes
+        assert_close(rating.mu, 1464.06, 0.01);
+        assert_close(rating.phi, 151.52, 0.01);
+        assert_close(rating.sigma, 0.05999, 0.00001);
+
+        // Opponents are only read, never updated
+        assert_eq!(opponent_1.mu, 1400.0);
+        assert_eq!(opponent_1.phi, 30.0);
+    }
+
+    #[test]
+    fn paper_example_explain() {
+        let rating = test_rating(&TUNING, 1500.0, 200.0);
+        let opponent_1 = test_rating(&TUNING, 1400.0, 30.0);
+        let opponent_2 = test_rating(&TUNING, 1550.0, 100.0);
+        let opponent_3 = test_rating(&TUNING, 1700.0, 300.0);
+
+        let explanation = algorithm::explain_rate(
+            &rating,
+            vec![
+                (Outcome::Win, &opponent_1),
+                (Outcome::Loss, &opponent_2),
+                (Outcome::Loss, &opponent_3),
+            ],
+        );
+
+        for (game, (impact, expected)) in
+            explanation
+                .games
+                .iter()
+                .zip([(0.9955, 0.639), (0.9531, 0.432), (0.7242, 0.303)])
+        {
+            assert_close(game.impact, impact, 0.0001);
+            assert_close(game.expected_score, expected, 0.001);
+        }
+        assert_close(explanation.variance, 1.7785, 0.001);
+        assert_close(explanation.difference, -0.4834, 0.001);
+        assert_close(explanation.sigma, 0.05999, 0.00001);
+        assert_close(explanation.phi_star / 173.7178, 1.152862, 0.0001);
+
+        // The per-game changes add up to the total change
+        let total: f64 = explanation.games.iter().map(|game| game.mu_change).sum();
+        assert_close(total, explanation.rating.mu - 1500.0, 1e-9);
+        assert!(explanation.games[0].mu_change > 0.0);
+        assert!(explanation.games[2].mu_change < 0.0);
+        let variance_inv: f64 = explanation
+            .games
+            .iter()
+            .map(|game| game.variance_contribution)
+            .sum();
+        assert_close(1.0 / variance_inv, explanation.variance, 1e-12);
+    }
+
+    #[test]
+    fn explain_matches_rate() {
+        let rating = test_rating(&TUNING, 1620.0, 80.0);
+        let mut opponent = test_rating(&TUNING, 1480.0, 150.0);
+        let explanation = algorithm::explain_rate(&rating, vec![(Outcome::Draw, &opponent)]);
+
+        let mut rated = rating;
+        let report = algorithm::try_rate(&mut rated, vec![(Outcome::Draw, &mut opponent)]).unwrap();
+        assert_eq!(explanation.rating.mu, rated.mu);
+        assert_eq!(explanation.rating.phi, rated.phi);
+        assert_eq!(explanation.rating.sigma, rated.sigma);
+        assert_eq!(explanation.report, report);
+        assert_eq!(rating.mu, 1620.0);
+    }
+
+    #[test]
+    fn paper_example_period() {
+        let mut ratings = HashMap::new();
+        ratings.insert(0, test_rating(&TUNING, 1500.0, 200.0));
+        ratings.insert(1, test_rating(&TUNING, 1400.0, 30.0));
+        ratings.insert(2, test_rating(&TUNING, 1550.0, 100.0));
+        ratings.insert(3, test_rating(&TUNING, 1700.0, 300.0));
+
+        let mut period = RatingPeriod::new();
+        period.add_game(0, 1, Outcome::Win);
+        period.add_game(0, 2, Outcome::Loss);
+        period.add_game(0, 3, Outcome::Loss);
+        period.rate(&mut ratings);
+
+        assert_close(ratings[&0].mu, 1464.06, 0.01);
+        assert_close(ratings[&0].phi, 151.52, 0.01);
+        assert_close(ratings[&0].sigma, 0.05999, 0.00001);
+
+        // Each opponent played a single game against the 1500/200 player
+        assert_close(ratings[&1].mu, 1398.1436, 0.0001);
+        assert_close(ratings[&1].phi, 31.6702, 0.0001);
+        assert_close(ratings[&2].mu, 1570.3947, 0.0001);
+        assert_close(ratings[&2].phi, 97.7092, 0.0001);
+        assert_close(ratings[&3].mu, 1784.4218, 0.0001);
+        assert_close(ratings[&3].phi, 251.5656, 0.0001);
+    }
+
+    #[test]
+    fn paper_example_compete() {
+        let mut rating = test_rating(&TUNING, 1500.0, 200.0);
+        let mut opponent = test_rating(&TUNING, 1400.0, 30.0);
+
+        game::compete(&mut rating, &mut opponent, false);
+
+        // Reference values from the paper's formulas applied to the first game alone
+        assert_close(rating.mu, 1563.5642, 0.0001);
+        assert_close(rating.phi, 175.4027, 0.0001);
+        assert_close(rating.sigma, 0.059999, 0.000001);
+        assert_close(opponent.mu, 1398.1436, 0.0001);
+        assert_close(opponent.phi, 31.6702, 0.0001);
+        assert_close(opponent.sigma, 0.059999, 0.000001);
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        algorithm,
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::{self, Outcome},
+        period::RatingPeriod,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn no_outcomes() {
+        let mut rating = Rating::new(&TUNING);
+        assert_eq!(
+            algorithm::try_rate(&mut rating, vec![]),
+            Err(GlickoError::NoOutcomes)
+        );
+    }
+
+    #[test]
+    fn non_finite() {
+        let mut rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        other_rating.mu = f64::INFINITY;
+        assert_eq!(
+            algorithm::try_rate(&mut rating, vec![(Outcome::Win, &mut other_rating)]),
+            Err(GlickoError::NonFinite("mu"))
+        );
+        assert_eq!(rating.mu, MU);
+        assert_eq!(rating.phi, PHI);
+    }
+
+    #[test]
+    fn non_positive() {
+        let tuning = Tuning::new(MU, PHI, SIGMA, 0.0);
+        let mut rating = Rating::new(&tuning);
+        let mut other_rating = Rating::new(&TUNING);
+        assert_eq!(
+            algorithm::try_rate(&mut rating, vec![(Outcome::Win, &mut other_rating)]),
+            Err(GlickoError::NonPositive("tuning.tau"))
+        );
+
+        let mut rating = Rating::new(&TUNING);
+        rating.sigma = -0.1;
+        assert_eq!(
+            game::try_odds(&rating, &other_rating),
+            Err(GlickoError::NonPositive("sigma"))
+        );
+    }
+
+    #[test]
+    fn invalid_outcome() {
+        let mut rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        assert_eq!(
+            algorithm::try_rate(&mut rating, vec![(Outcome::Score(2.0), &mut other_rating)]),
+            Err(GlickoError::InvalidOutcome(2.0))
+        );
+        assert_eq!(
+            algorithm::try_rate_weighted(
+                &mut rating,
+                vec![(Outcome::Win, f64::NAN, &mut other_rating)]
+            )
+            .map_err(|error| matches!(error, GlickoError::InvalidWeight(_))),
+            Err(true)
+        );
+    }
+
+    #[test]
+    fn compete_is_atomic() {
+        let mut rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        other_rating.phi = f64::NAN;
+        assert!(game::try_compete(&mut rating, &mut other_rating, true).is_err());
+        assert_eq!(rating.mu, MU);
+        assert_eq!(rating.phi, PHI);
+    }
+
+    #[test]
+    fn period_is_atomic() {
+        let mut ratings = HashMap::new();
+        ratings.insert("a", Rating::new(&TUNING));
+        ratings.insert("b", Rating::new(&TUNING));
+
+        let mut period = RatingPeriod::new();
+        period.add_game("a", "b", Outcome::Win);
+        period.add_game("a", "missing", Outcome::Win);
+        assert_eq!(
+            period.try_rate(&mut ratings),
+            Err(GlickoError::UnknownPlayer)
+        );
+        assert_eq!(ratings["a"].mu, MU);
+        assert_eq!(ratings["b"].mu, MU);
+    }
+
+    #[test]
+    fn extreme_ratings_do_not_hang() {
+        let mut rating = Rating::new(&TUNING);
+        let mut other_rating = Rating::new(&TUNING);
+        rating.mu = 1e12;
+        rating.phi = 1e-12;
+        // Either a finite rating or an error, but never a hang or a NaN
+        if algorithm::try_rate(&mut rating, vec![(Outcome::Loss, &mut other_rating)]).is_ok() {
+            assert!(rating.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            GlickoError::NonPositive("phi").to_string(),
+            "phi must be greater than zero!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod solver_tests {
+    use crate::glicko2::{
+        algorithm,
+        error::GlickoError,
+        game::Outcome,
+        rating::Rating,
+        solver::{SolverConfig, SolverMethod, SolverReport},
+        tuning::Tuning,
+    };
+
+    fn paper_example(tuning: &Tuning) -> (Rating, Result<SolverReport, GlickoError>) {
+        let player = |mu, phi| Rating {
+            mu,
+            phi,
+            sigma: 0.06,
+            tuning: *tuning,
+        };
+        let mut rating = player(1500.0, 200.0);
+        let report = algorithm::try_rate(
+            &mut rating,
+            vec![
+                (Outcome::Win, &mut player(1400.0, 30.0)),
+                (Outcome::Loss, &mut player(1550.0, 100.0)),
+                (Outcome::Loss, &mut player(1700.0, 300.0)),
+            ],
+        );
+        (rating, report)
+    }
+
+    #[test]
+    fn default_config() {
+        let tuning = Tuning::new(1500.0, 350.0, 0.06, 0.5);
+        assert_eq!(tuning.solver, SolverConfig::default());
+        assert_eq!(tuning.solver.method, SolverMethod::Illinois);
+    }
+
+    #[test]
+    fn methods_agree() {
+        for method in [
+            SolverMethod::Illinois,
+            SolverMethod::Bisection,
+            SolverMethod::Newton,
+        ] {
+            let tuning = Tuning::new(1500.0, 350.0, 0.06, 0.5)
+                .with_solver(SolverConfig::new(1e-10, 1000, method));
+            let (rating, report) = paper_example(&tuning);
+            let report = report.unwrap();
+            println!("{:?}: {:?}", method, report);
+            assert!(report.converged);
+            assert!(report.iterations > 0);
+            assert!((rating.mu - 1464.05).abs() < 0.01);
+            assert!((rating.phi - 151.52).abs() < 0.01);
+            assert!((rating.sigma - 0.059996).abs() < 0.000001);
+        }
+    }
+
+    #[test]
+    fn exact_root() {
+        // The Illinois step lands exactly on the root of the criterion
+        let tuning = Tuning::default();
+        let mut rating = Rating::new(&tuning);
+        rating.mu = 1000.0;
+        rating.sigma = 1.0;
+        let mut opponent = Rating::new(&tuning);
+        let report = algorithm::try_rate(&mut rating, vec![(Outcome::Win, &mut opponent)]).unwrap();
+        assert!(report.converged);
+        assert!(rating.mu > 1000.0);
+    }
+
+    #[test]
+    fn iteration_limit() {
+        let tuning = Tuning::new(1500.0, 350.0, 0.06, 0.5).with_solver(SolverConfig::new(
+            1e-12,
+            1,
+            SolverMethod::Bisection,
+        ));
+        let (rating, report) = paper_example(&tuning);
+        assert_eq!(
+            report,
+            Err(GlickoError::NonConvergence(SolverReport {
+                iterations: 1,
+                converged: false
+            }))
+        );
+        assert_eq!(rating.mu, 1500.0);
+    }
+
+    #[test]
+    fn invalid_config() {
+        let tuning =
+            Tuning::default().with_solver(SolverConfig::new(0.0, 10, SolverMethod::Newton));
+        assert_eq!(
+            tuning.validate(),
+            Err(GlickoError::NonPositive("tuning.solver.tolerance"))
+        );
+        let tuning =
+            Tuning::default().with_solver(SolverConfig::new(1e-6, 0, SolverMethod::Newton));
+        assert_eq!(
+            tuning.validate(),
+            Err(GlickoError::NonPositive("tuning.solver.max_iterations"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod draw_tests {
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::{self, Outcome},
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn no_draws_matches_odds() {
+        let rating_1 = Rating {
+            mu: 1600.0,
+            phi: 200.0,
+            sigma: SIGMA,
+            tuning: TUNING,
+        };
+        let rating_2 = Rating::new(&TUNING);
+        let probabilities = game::outcome_probabilities(&rating_1, &rating_2);
+        assert!((probabilities.win - game::odds(&rating_1, &rating_2)).abs() < 1e-12);
+        assert_eq!(probabilities.draw, 0.0);
+        assert!((probabilities.win + probabilities.loss - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn probabilities_sum_to_one() {
+        let tuning = TUNING.with_draw_parameter(0.7);
+        let rating_1 = Rating {
+            mu: 1800.0,
+            phi: 80.0,
+            sigma: SIGMA,
+            tuning,
+        };
+        let rating_2 = Rating::new(&tuning);
+        let probabilities = game::outcome_probabilities(&rating_1, &rating_2);
+        assert!((probabilities.win + probabilities.draw + probabilities.loss - 1.0).abs() < 1e-12);
+        assert!(probabilities.win > probabilities.loss);
+
+        let reversed = game::outcome_probabilities(&rating_2, &rating_1);
+        assert!((reversed.win - probabilities.loss).abs() < 1e-12);
+        assert!((reversed.draw - probabilities.draw).abs() < 1e-12);
+    }
+
+    #[test]
+    fn even_match() {
+        let tuning = TUNING.with_draw_parameter(0.5);
+        let rating = Rating::new(&tuning);
+        let probabilities = game::outcome_probabilities(&rating, &rating);
+        assert!((probabilities.draw - 0.5 / 2.5).abs() < 1e-12);
+        assert!((probabilities.win - probabilities.loss).abs() < 1e-12);
+        assert!((probabilities.expected_score() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fit_recovers_draw_parameter() {
+        let tuning = TUNING.with_draw_parameter(0.4);
+        let strong = Rating {
+            mu: 1700.0,
+            phi: 100.0,
+            sigma: SIGMA,
+            tuning,
+        };
+        let weak = Rating::new(&tuning);
+
+        // Build a history whose outcome frequencies match the model exactly
+        let probabilities = game::outcome_probabilities(&strong, &weak);
+        let mut history = vec![];
+        let scale = 100_000.0;
+        for (outcome, probability) in [
+            (Outcome::Win, probabilities.win),
+            (Outcome::Draw, probabilities.draw),
+            (Outcome::Loss, probabilities.loss),
+        ] {
+            for _ in 0..(probability * scale).round() as usize {
+                history.push((strong, weak, outcome));
+            }
+        }
+
+        let fitted = game::fit_draw_parameter(&history).unwrap();
+        assert!((fitted - 0.4).abs() < 1e-3);
+    }
+
+    #[test]
+    fn fit_edge_cases() {
+        let rating = Rating::new(&TUNING);
+        assert_eq!(game::fit_draw_parameter(&[]), None);
+        assert_eq!(
+            game::fit_draw_parameter(&[(rating, rating, Outcome::Draw)]),
+            None
+        );
+        assert_eq!(
+            game::fit_draw_parameter(&[(rating, rating, Outcome::Win)]),
+            Some(0.0)
+        );
+        assert_eq!(
+            game::fit_draw_parameter(&[(rating, rating, Outcome::Score(0.25))]),
+            None
+        );
+    }
+
+    #[test]
+    fn fit_skips_invalid_ratings() {
+        let rating = Rating::new(&TUNING);
+        let mut invalid = rating;
+        invalid.mu = f64::NAN;
+        let history = [
+            (rating, rating, Outcome::Draw),
+            (rating, rating, Outcome::Win),
+        ];
+        let with_invalid = [
+            (rating, rating, Outcome::Draw),
+            (rating, rating, Outcome::Win),
+            (invalid, rating, Outcome::Win),
+        ];
+        assert_eq!(
+            game::fit_draw_parameter(&with_invalid),
+            game::fit_draw_parameter(&history)
+        );
+        assert!(game::fit_draw_parameter(&with_invalid).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn negative_draw_parameter() {
+        let tuning = TUNING.with_draw_parameter(-1.0);
+        let rating = Rating::new(&tuning);
+        assert_eq!(
+            game::try_outcome_probabilities(&rating, &rating),
+            Err(GlickoError::Negative("tuning.draw_parameter"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod advantage_tests {
+    use crate::{
+        glicko2::{
+            algorithm,
+            constants::{MU, PHI, SIGMA, TAU},
+            error::GlickoError,
+            game::{self, Outcome, Side},
+            tuning::Tuning,
+        },
+        test_rating,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn no_advantage_matches_neutral() {
+        let rating_1 = test_rating(&TUNING, 1550.0, 120.0);
+        let rating_2 = test_rating(&TUNING, 1500.0, 120.0);
+        assert_eq!(
+            game::odds_sided(&rating_1, &rating_2, Side::Home),
+            game::odds(&rating_1, &rating_2)
+        );
+    }
+
+    #[test]
+    fn odds_shift_by_advantage() {
+        let tuning = TUNING.with_advantage(100.0);
+        let home = test_rating(&tuning, 1500.0, 120.0);
+        let away = test_rating(&tuning, 1500.0, 120.0);
+        let boosted = test_rating(&tuning, 1600.0, 120.0);
+        let odds = game::odds_sided(&home, &away, Side::Home);
+        assert!((odds - game::odds(&boosted, &away)).abs() < 1e-12);
+        assert!((game::odds_sided(&away, &home, Side::Away) - (1.0 - odds)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rate_shifts_by_advantage() {
+        let tuning = TUNING.with_advantage(80.0);
+        let mut home = test_rating(&tuning, 1500.0, 120.0);
+        let mut opponent = test_rating(&tuning, 1550.0, 120.0);
+        algorithm::rate_sided(&mut home, vec![(Outcome::Win, Side::Home, &mut opponent)]);
+
+        let mut expected = test_rating(&tuning, 1500.0, 120.0);
+        let mut weaker_opponent = test_rating(&tuning, 1470.0, 120.0);
+        algorithm::rate(&mut expected, vec![(Outcome::Win, &mut weaker_opponent)]);
+        assert!((home.mu - expected.mu).abs() < 1e-9);
+        assert!((home.phi - expected.phi).abs() < 1e-9);
+    }
+
+    #[test]
+    fn home_win_counts_for_less() {
+        let tuning = TUNING.with_advantage(60.0);
+        let mut home_winner = test_rating(&tuning, 1500.0, 120.0);
+        let mut away_loser = test_rating(&tuning, 1500.0, 120.0);
+        game::compete_sided(&mut home_winner, &mut away_loser, false, Side::Home);
+
+        let mut neutral_winner = test_rating(&tuning, 1500.0, 120.0);
+        let mut neutral_loser = test_rating(&tuning, 1500.0, 120.0);
+        game::compete(&mut neutral_winner, &mut neutral_loser, false);
+
+        assert!(home_winner.mu < neutral_winner.mu);
+        assert!(away_loser.mu > neutral_loser.mu);
+    }
+
+    #[test]
+    fn fit_recovers_advantage() {
+        let tuning = TUNING.with_advantage(60.0);
+        let home = test_rating(&tuning, 1520.0, 120.0);
+        let away = test_rating(&tuning, 1480.0, 120.0);
+        let odds = game::odds_sided(&home, &away, Side::Home);
+
+        // Build a history whose home results match the model exactly
+        let games = 100_000;
+        let wins = (odds * games as f64).round() as usize;
+        let mut history = vec![(home, away, Outcome::Win); wins];
+        history.extend(vec![(home, away, Outcome::Loss); games - wins]);
+
+        let advantage = game::fit_advantage(&history).unwrap();
+        assert!((advantage - 60.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn fit_edge_cases() {
+        let rating = test_rating(&TUNING, MU, 120.0);
+        assert_eq!(game::fit_advantage(&[]), None);
+        assert_eq!(game::fit_advantage(&[(rating, rating, Outcome::Win)]), None);
+        let advantage = game::fit_advantage(&[
+            (rating, rating, Outcome::Draw),
+            (rating, rating, Outcome::Score(2.0)),
+        ])
+        .unwrap();
+        assert!(advantage.abs() < 1e-3);
+    }
+
+    #[test]
+    fn fit_skips_invalid_ratings() {
+        let rating = test_rating(&TUNING, MU, 120.0);
+        let mut invalid = rating;
+        invalid.phi = f64::NAN;
+        let advantage = game::fit_advantage(&[
+            (rating, rating, Outcome::Draw),
+            (invalid, rating, Outcome::Win),
+        ])
+        .unwrap();
+        assert!(advantage.abs() < 1e-3);
+        assert_eq!(
+            game::fit_advantage(&[(invalid, rating, Outcome::Draw)]),
+            None
+        );
+    }
+
+    #[test]
+    fn non_finite_advantage() {
+        let tuning = TUNING.with_advantage(f64::NAN);
+        let rating = test_rating(&tuning, MU, 120.0);
+        assert_eq!(
+            game::try_odds_sided(&rating, &rating, Side::Home),
+            Err(GlickoError::NonFinite("tuning.advantage"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod margin_tests {
+    use crate::glicko2::{
+        algorithm,
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::{self, Outcome},
+        margin::{MarginLink, MarginOfVictory},
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn rate_margin(tuning: Tuning, mu: f64, outcome: Outcome, margin: f64) -> Rating {
+        let mut rating = Rating::new(&tuning);
+        rating.mu = mu;
+        let mut opponent = Rating::new(&tuning);
+        algorithm::rate_margin(&mut rating, vec![(outcome, margin, &mut opponent)]);
+        rating
+    }
+
+    fn rate(tuning: Tuning, mu: f64, outcome: Outcome) -> Rating {
+        let mut rating = Rating::new(&tuning);
+        rating.mu = mu;
+        let mut opponent = Rating::new(&tuning);
+        algorithm::rate(&mut rating, vec![(outcome, &mut opponent)]);
+        rating
+    }
+
+    #[test]
+    fn ignored_without_config() {
+        let rated = rate_margin(TUNING, MU, Outcome::Win, 10.0);
+        let expected = rate(TUNING, MU, Outcome::Win);
+        assert_eq!(rated.mu, expected.mu);
+        assert_eq!(rated.phi, expected.phi);
+    }
+
+    #[test]
+    fn one_point_win_is_regular() {
+        let tuning = TUNING.with_margin(MarginOfVictory::default());
+        let rated = rate_margin(tuning, MU, Outcome::Win, 1.0);
+        let expected = rate(tuning, MU, Outcome::Win);
+        assert!((rated.mu - expected.mu).abs() < 1e-9);
+    }
+
+    #[test]
+    fn larger_margins_move_further() {
+        let tuning = TUNING.with_margin(MarginOfVictory::default());
+        let stomp = rate_margin(tuning, MU, Outcome::Win, 10.0);
+        let squeaker = rate_margin(tuning, MU, Outcome::Win, 1.0);
+        assert!(stomp.mu > squeaker.mu);
+
+        let blowout_loss = rate_margin(tuning, MU, Outcome::Loss, 10.0);
+        let close_loss = rate_margin(tuning, MU, Outcome::Loss, 1.0);
+        assert!(blowout_loss.mu < close_loss.mu);
+    }
+
+    #[test]
+    fn multiplier_is_bounded() {
+        let tuning =
+            TUNING.with_margin(MarginOfVictory::new(MarginLink::Linear(1.0), 0.0, 0.5, 2.0));
+        let huge = rate_margin(tuning, MU, Outcome::Win, 1e6);
+        let double = rate_margin(tuning, MU, Outcome::Win, 2.0);
+        assert!((huge.mu - double.mu).abs() < 1e-9);
+
+        let tiny = rate_margin(tuning, MU, Outcome::Win, 0.01);
+        let half = rate_margin(tuning, MU, Outcome::Win, 0.5);
+        assert!((tiny.mu - half.mu).abs() < 1e-9);
+    }
+
+    #[test]
+    fn favorites_are_not_over_rewarded() {
+        let corrected = TUNING.with_margin(MarginOfVictory::default());
+        let uncorrected = TUNING.with_margin(MarginOfVictory {
+            autocorrelation: 0.0,
+            ..MarginOfVictory::default()
+        });
+        let favorite = rate_margin(corrected, 1800.0, Outcome::Win, 5.0);
+        let uncorrected_favorite = rate_margin(uncorrected, 1800.0, Outcome::Win, 5.0);
+        assert!(favorite.mu < uncorrected_favorite.mu);
+
+        let underdog = rate_margin(corrected, 1200.0, Outcome::Win, 5.0);
+        let uncorrected_underdog = rate_margin(uncorrected, 1200.0, Outcome::Win, 5.0);
+        assert!(underdog.mu > uncorrected_underdog.mu);
+    }
+
+    #[test]
+    fn zero_margin_is_draw() {
+        let tuning = TUNING.with_margin(MarginOfVictory::default());
+        let mut rating_1 = Rating::new(&tuning);
+        let mut rating_2 = Rating::new(&tuning);
+        rating_1.mu = 1600.0;
+        let mut expected_1 = rating_1;
+        let mut expected_2 = rating_2;
+        game::compete_margin(&mut rating_1, &mut rating_2, 0.0);
+        game::compete(&mut expected_1, &mut expected_2, true);
+        assert_eq!(rating_1.mu, expected_1.mu);
+        assert_eq!(rating_2.mu, expected_2.mu);
+    }
+
+    #[test]
+    fn invalid_margins() {
+        let tuning = TUNING.with_margin(MarginOfVictory::default());
+        let mut rating = Rating::new(&tuning);
+        let mut opponent = Rating::new(&tuning);
+        assert_eq!(
+            algorithm::try_rate_margin(&mut rating, vec![(Outcome::Win, -1.0, &mut opponent)]),
+            Err(GlickoError::Negative("margin"))
+        );
+
+        let tuning =
+            TUNING.with_margin(MarginOfVictory::new(MarginLink::Linear(0.0), 0.0, 0.5, 2.0));
+        assert_eq!(
+            tuning.validate(),
+            Err(GlickoError::NonPositive("tuning.margin.link"))
+        );
+
+        let tuning =
+            TUNING.with_margin(MarginOfVictory::new(MarginLink::Logarithmic, 0.0, 2.0, 1.5));
+        assert_eq!(
+            tuning.validate(),
+            Err(GlickoError::InvalidRange("tuning.margin.min_multiplier"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod glicko1_tests {
+    use crate::{
+        glicko2::{
+            algorithm,
+            constants::{MU, PHI, SIGMA, TAU},
+            error::GlickoError,
+            game::{self, Outcome},
+            rating::Rating,
+            tuning::{Algorithm, Tuning},
+        },
+        test_rating,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn paper_example() {
+        // Example from Glickman's "The Glicko system", with no time passing
+        let tuning = TUNING.with_algorithm(Algorithm::Glicko1 { c: 0.0 });
+        let mut player = test_rating(&tuning, 1500.0, 200.0);
+        let mut opponent_1 = test_rating(&tuning, 1400.0, 30.0);
+        let mut opponent_2 = test_rating(&tuning, 1550.0, 100.0);
+        let mut opponent_3 = test_rating(&tuning, 1700.0, 300.0);
+        let report = algorithm::try_rate(
+            &mut player,
+            vec![
+                (Outcome::Win, &mut opponent_1),
+                (Outcome::Loss, &mut opponent_2),
+                (Outcome::Loss, &mut opponent_3),
+            ],
+        )
+        .unwrap();
+        assert!((player.mu - 1464.1).abs() < 0.1);
+        assert!((player.phi - 151.4).abs() < 0.1);
+        assert_eq!(player.sigma, SIGMA);
+        assert_eq!(report.iterations, 0);
+    }
+
+    #[test]
+    fn decay_grows_by_c() {
+        let tuning = TUNING.with_algorithm(Algorithm::Glicko1 { c: 40.0 });
+        let mut player = test_rating(&tuning, MU, 30.0);
+        player.decay();
+        assert!((player.phi - 50.0).abs() < 1e-9);
+
+        let mut player = test_rating(&tuning, MU, 349.0);
+        player.decay();
+        assert!((player.phi - PHI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shares_game_api() {
+        let tuning = TUNING.with_algorithm(Algorithm::Glicko1 { c: 20.0 });
+        let mut rating_1 = Rating::new(&tuning);
+        let mut rating_2 = Rating::new(&tuning);
+        game::compete(&mut rating_1, &mut rating_2, false);
+        assert!(rating_1.mu > MU);
+        assert!((rating_1.mu - MU + rating_2.mu - MU).abs() < 1e-9);
+        assert!(game::odds(&rating_1, &rating_2) > 0.5);
+    }
+
+    #[test]
+    fn round_trip() {
+        let rating_2 = test_rating(&TUNING, 1620.0, 80.0);
+        let rating_1 = rating_2.to_algorithm(Algorithm::Glicko1 { c: 30.0 });
+        assert_eq!(rating_1.mu, rating_2.mu);
+        assert_eq!(rating_1.phi, rating_2.phi);
+        assert_eq!(rating_1.tuning().algorithm, Algorithm::Glicko1 { c: 30.0 });
+
+        let back = rating_1.to_algorithm(Algorithm::Glicko2);
+        assert_eq!(back.mu, rating_2.mu);
+        assert_eq!(back.sigma, rating_2.sigma);
+        assert_eq!(back.tuning(), rating_2.tuning());
+    }
+
+    #[test]
+    fn negative_c() {
+        let tuning = TUNING.with_algorithm(Algorithm::Glicko1 { c: -1.0 });
+        assert_eq!(
+            Rating::new(&tuning).validate(),
+            Err(GlickoError::Negative("tuning.algorithm.c"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod elo_tests {
+    use crate::glicko2::{
+        algorithm,
+        constants::{MU, PHI, SIGMA, TAU},
+        elo::{self, KFactor},
+        error::GlickoError,
+        game::{self, Outcome},
+        rating::Rating,
+        tuning::{Algorithm, Tuning},
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn elo_tuning(k: f64) -> Tuning {
+        TUNING.with_algorithm(Algorithm::Elo {
+            k: KFactor::Constant(k),
+        })
+    }
+
+    #[test]
+    fn classic_update() {
+        let tuning = elo_tuning(32.0);
+        let mut favorite = elo::from_elo(1600.0, 200.0, &tuning);
+        let mut underdog = elo::from_elo(1400.0, 50.0, &tuning);
+        let expected = 1.0 / (1.0 + 10f64.powf(-200.0 / 400.0));
+        game::compete(&mut favorite, &mut underdog, false);
+
+        assert!((favorite.mu - (1600.0 + 32.0 * (1.0 - expected))).abs() < 1e-6);
+        assert!((underdog.mu - (1400.0 - 32.0 * (1.0 - expected))).abs() < 1e-6);
+        assert!((favorite.phi - 200.0).abs() < 1e-9);
+        assert_eq!(favorite.sigma, SIGMA);
+    }
+
+    #[test]
+    fn odds_ignore_deviation() {
+        let tuning = elo_tuning(20.0);
+        let rating_1 = elo::from_elo(1700.0, 300.0, &tuning);
+        let rating_2 = elo::from_elo(1500.0, 30.0, &tuning);
+        let expected = 1.0 / (1.0 + 10f64.powf(-200.0 / 400.0));
+        assert!((game::odds(&rating_1, &rating_2) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn tiered_k_factor() {
+        let tuning = TUNING.with_algorithm(Algorithm::Elo {
+            k: KFactor::Tiered {
+                below: 20.0,
+                threshold: 2400.0,
+                above: 10.0,
+            },
+        });
+        let mut master = elo::from_elo(2450.0, PHI, &tuning);
+        let mut expert = elo::from_elo(2000.0, PHI, &tuning);
+        let mut opponent = elo::from_elo(2450.0, PHI, &tuning);
+        algorithm::rate(&mut master, vec![(Outcome::Win, &mut opponent)]);
+        let mut opponent = elo::from_elo(2000.0, PHI, &tuning);
+        algorithm::rate(&mut expert, vec![(Outcome::Win, &mut opponent)]);
+        assert!((master.mu - 2455.0).abs() < 1e-6);
+        assert!((expert.mu - 2010.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn conversions() {
+        let rating = elo::from_elo(1850.0, 120.0, &TUNING);
+        assert_eq!(elo::to_elo(&rating), 1850.0);
+        assert_eq!(rating.phi, 120.0);
+        assert_eq!(rating.sigma, SIGMA);
+    }
+
+    #[test]
+    fn invalid_k_factor() {
+        let tuning = elo_tuning(0.0);
+        assert_eq!(
+            Rating::new(&tuning).validate(),
+            Err(GlickoError::NonPositive("tuning.algorithm.k"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod team_tests {
+    use crate::{
+        glicko2::{
+            algorithm,
+            constants::{MU, PHI, SIGMA, TAU},
+            error::GlickoError,
+            game::Outcome,
+            rating::Rating,
+            team::{self, Aggregation},
+            tuning::Tuning,
+        },
+        test_rating,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn composite_mean() {
+        let members = [
+            (test_rating(&TUNING, 1400.0, 100.0), 1.0),
+            (test_rating(&TUNING, 1600.0, 100.0), 1.0),
+        ];
+        let composite = team::composite(&members, Aggregation::Mean);
+        assert!((composite.mu - 1500.0).abs() < 1e-9);
+        assert!((composite.phi - 100.0 / 2.0_f64.sqrt()).abs() < 1e-9);
+        assert!((composite.sigma - SIGMA).abs() < 1e-12);
+    }
+
+    #[test]
+    fn composite_sum() {
+        let members = [
+            (test_rating(&TUNING, 1600.0, 30.0), 1.0),
+            (test_rating(&TUNING, 1700.0, 40.0), 1.0),
+        ];
+        let composite = team::composite(&members, Aggregation::Sum);
+        assert!((composite.mu - 1800.0).abs() < 1e-9);
+        assert!((composite.phi - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn composite_playtime() {
+        let members = [
+            (test_rating(&TUNING, 1400.0, 100.0), 3.0),
+            (test_rating(&TUNING, 1800.0, 100.0), 1.0),
+        ];
+        let composite = team::composite(&members, Aggregation::Playtime);
+        assert!((composite.mu - 1500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn composite_max_uncertainty() {
+        let members = [
+            (test_rating(&TUNING, 1400.0, 50.0), 1.0),
+            (test_rating(&TUNING, 1600.0, 200.0), 1.0),
+        ];
+        let composite = team::composite(&members, Aggregation::MaxUncertainty);
+        assert!((composite.mu - 1500.0).abs() < 1e-9);
+        assert!((composite.phi - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn single_member_matches_rate() {
+        let mut members = [(test_rating(&TUNING, 1550.0, 120.0), 1.0)];
+        let opponent = test_rating(&TUNING, 1450.0, 80.0);
+        team::rate(
+            &mut members,
+            vec![(Outcome::Win, opponent)],
+            Aggregation::Mean,
+        );
+
+        let mut expected = test_rating(&TUNING, 1550.0, 120.0);
+        let mut other = opponent;
+        algorithm::rate(&mut expected, vec![(Outcome::Win, &mut other)]);
+        assert!((members[0].0.mu - expected.mu).abs() < 1e-9);
+        assert!((members[0].0.phi - expected.phi).abs() < 1e-9);
+        assert!((members[0].0.sigma - expected.sigma).abs() < 1e-12);
+    }
+
+    #[test]
+    fn split_matches_composite_change() {
+        let aggregation = Aggregation::Playtime;
+        let mut members = [
+            (test_rating(&TUNING, 1500.0, 300.0), 40.0),
+            (test_rating(&TUNING, 1650.0, 60.0), 40.0),
+            (test_rating(&TUNING, 1450.0, 150.0), 20.0),
+        ];
+        let opponent = test_rating(&TUNING, 1600.0, 100.0);
+        let before = team::composite(&members, aggregation);
+        let mut expected = before;
+        let mut other = opponent;
+        algorithm::rate(&mut expected, vec![(Outcome::Win, &mut other)]);
+
+        let phis: Vec<f64> = members.iter().map(|(rating, _)| rating.phi).collect();
+        team::rate(&mut members, vec![(Outcome::Win, opponent)], aggregation);
+        let after = team::composite(&members, aggregation);
+        assert!((after.mu - expected.mu).abs() < 1e-9);
+        for ((rating, _), phi) in members.iter().zip(phis) {
+            assert!(rating.phi < phi);
+        }
+    }
+
+    #[test]
+    fn uncertain_members_move_further() {
+        let mut winners = [
+            (test_rating(&TUNING, 1500.0, 300.0), 1.0),
+            (test_rating(&TUNING, 1500.0, 50.0), 1.0),
+        ];
+        let mut losers = [
+            (test_rating(&TUNING, 1500.0, 100.0), 1.0),
+            (test_rating(&TUNING, 1500.0, 100.0), 1.0),
+        ];
+        team::compete(&mut winners, &mut losers, false, Aggregation::Mean);
+
+        assert!(winners[0].0.mu > winners[1].0.mu);
+        assert!(winners[1].0.mu > 1500.0);
+        assert!(300.0 - winners[0].0.phi > 50.0 - winners[1].0.phi);
+        assert!(losers[0].0.mu < 1500.0);
+        assert!((losers[0].0.mu - losers[1].0.mu).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_teams() {
+        let mut members: [(Rating, f64); 0] = [];
+        assert_eq!(
+            team::try_rate(
+                &mut members,
+                vec![(Outcome::Win, test_rating(&TUNING, MU, PHI))],
+                Aggregation::Mean
+            ),
+            Err(GlickoError::EmptyTeam)
+        );
+        assert_eq!(
+            team::try_composite(&[(test_rating(&TUNING, MU, PHI), 0.0)], Aggregation::Mean)
+                .unwrap_err(),
+            GlickoError::InvalidWeight(0.0)
+        );
+
+        let mut winners = [(test_rating(&TUNING, MU, PHI), 1.0)];
+        let mut losers = [
+            (test_rating(&TUNING, MU, PHI), 1.0),
+            (test_rating(&TUNING, MU, f64::NAN), 1.0),
+        ];
+        assert_eq!(
+            team::try_compete(&mut winners, &mut losers, false, Aggregation::Sum),
+            Err(GlickoError::NonFinite("phi"))
+        );
+        assert_eq!(winners[0].0.mu, MU);
+        assert_eq!(losers[0].0.mu, MU);
+    }
+}
+
+#[cfg(test)]
+mod multiplayer_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        algorithm,
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::{self, Outcome},
+        multiplayer::{self, Weighting},
+        period::RatingPeriod,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn lobby() -> HashMap<&'static str, Rating> {
+        let mut ratings = HashMap::new();
+        for (id, mu) in [
+            ("a", 1700.0),
+            ("b", 1500.0),
+            ("c", 1400.0),
+            ("idle", 1500.0),
+        ] {
+            ratings.insert(
+                id,
+                Rating {
+                    mu,
+                    phi: 150.0,
+                    sigma: SIGMA,
+                    tuning: TUNING,
+                },
+            );
+        }
+        ratings
+    }
+
+    #[test]
+    fn two_players_match_compete() {
+        let mut ratings = lobby();
+        let mut winner = ratings["b"];
+        let mut loser = ratings["a"];
+        game::compete(&mut winner, &mut loser, false);
+
+        multiplayer::rate_placements(&[("a", 2), ("b", 1)], Weighting::Full, &mut ratings);
+        assert_eq!(ratings["b"].mu, winner.mu);
+        assert_eq!(ratings["b"].phi, winner.phi);
+        assert_eq!(ratings["a"].mu, loser.mu);
+    }
+
+    #[test]
+    fn add_too_few_placements() {
+        let mut period = RatingPeriod::new();
+        multiplayer::add_placements(&mut period, &[], Weighting::Normalized);
+        multiplayer::add_placements(&mut period, &[("a", 1)], Weighting::Normalized);
+        assert!(period.is_empty());
+    }
+
+    #[test]
+    fn normalized_weights() {
+        let mut ratings = lobby();
+        let mut expected = ratings["b"];
+        let mut a = ratings["a"];
+        let mut c = ratings["c"];
+        algorithm::rate_weighted(
+            &mut expected,
+            vec![(Outcome::Win, 0.5, &mut a), (Outcome::Draw, 0.5, &mut c)],
+        );
+
+        multiplayer::rate_placements(
+            &[("a", 2), ("b", 1), ("c", 1)],
+            Weighting::Normalized,
+            &mut ratings,
+        );
+        assert_eq!(ratings["b"].mu, expected.mu);
+        assert_eq!(ratings["b"].phi, expected.phi);
+    }
+
+    #[test]
+    fn full_weights_move_further() {
+        let placements = [("a", 3), ("b", 2), ("c", 1)];
+        let mut normalized = lobby();
+        let mut full = lobby();
+        multiplayer::rate_placements(&placements, Weighting::Normalized, &mut normalized);
+        multiplayer::rate_placements(&placements, Weighting::Full, &mut full);
+        assert!(full["c"].mu > normalized["c"].mu);
+        assert!(full["a"].mu < normalized["a"].mu);
+    }
+
+    #[test]
+    fn players_outside_lobby_untouched() {
+        let mut ratings = lobby();
+        let idle = ratings["idle"];
+        multiplayer::rate_placements(
+            &[("a", 1), ("b", 2), ("c", 3)],
+            Weighting::Fixed(0.25),
+            &mut ratings,
+        );
+        assert_eq!(ratings["idle"].mu, idle.mu);
+        assert_eq!(ratings["idle"].phi, idle.phi);
+    }
+
+    #[test]
+    fn invalid_lobbies() {
+        let mut ratings = lobby();
+        assert_eq!(
+            multiplayer::try_rate_placements(&[("a", 1)], Weighting::Full, &mut ratings),
+            Err(GlickoError::NoOutcomes)
+        );
+        assert_eq!(
+            multiplayer::try_rate_placements(
+                &[("a", 1), ("b", 2), ("a", 3)],
+                Weighting::Full,
+                &mut ratings
+            ),
+            Err(GlickoError::DuplicatePlayer)
+        );
+        assert_eq!(
+            multiplayer::try_rate_placements(
+                &[("a", 1), ("nobody", 2)],
+                Weighting::Full,
+                &mut ratings
+            ),
+            Err(GlickoError::UnknownPlayer)
+        );
+        assert_eq!(
+            multiplayer::try_rate_placements(
+                &[("a", 1), ("b", 2)],
+                Weighting::Fixed(-1.0),
+                &mut ratings
+            ),
+            Err(GlickoError::InvalidWeight(-1.0))
+        );
+        assert_eq!(ratings["a"].mu, 1700.0);
+    }
+}
+
+#[cfg(test)]
+mod system_tests {
+    use std::collections::HashMap;
+
+    use crate::glicko2::{
+        constants::{CONSERVATIVE_K, MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::Outcome,
+        period::RatingPeriod,
+        rating::Rating,
+        system::RatingSystem,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn lazy_players() {
+        let mut system = RatingSystem::new(TUNING);
+        assert!(system.is_empty());
+
+        system.add_game(1, 2, Outcome::Win);
+        assert_eq!(system.len(), 2);
+        assert!(system.contains(&1));
+        // Nothing is rated until the period closes
+        assert_eq!(system.get(&1).unwrap().mu, MU);
+        assert_eq!(system.period().len(), 1);
+        assert!(system.get(&3).is_none());
+    }
+
+    #[test]
+    fn matches_period() {
+        let mut system = RatingSystem::new(TUNING);
+        system.add_player("carol");
+        system.add_game("alice", "bob", Outcome::Win);
+        system.add_game("bob", "alice", Outcome::Draw);
+        system.close_period();
+
+        let mut ratings = HashMap::new();
+        for id in ["alice", "bob", "carol"] {
+            ratings.insert(id, Rating::new(&TUNING));
+        }
+        let mut period = RatingPeriod::new();
+        period.add_game("alice", "bob", Outcome::Win);
+        period.add_game("bob", "alice", Outcome::Draw);
+        period.rate(&mut ratings);
+
+        for (id, rating) in &system {
+            assert_eq!(rating.mu, ratings[id].mu);
+            assert_eq!(rating.phi, ratings[id].phi);
+        }
+        // Newcomers are already as uncertain as decay allows
+        assert_eq!(system.get(&"carol").unwrap().phi, PHI);
+        assert!(system.period().is_empty());
+    }
+
+    #[test]
+    fn ranking() {
+        let mut system = RatingSystem::new(TUNING);
+        system.add_game("alice", "bob", Outcome::Win);
+        system.add_game("carol", "bob", Outcome::Draw);
+        system.close_period();
+
+        let ranking: Vec<_> = system
+            .ranking(CONSERVATIVE_K)
+            .into_iter()
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(ranking, vec!["alice", "carol", "bob"]);
+    }
+
+    #[test]
+    fn failed_close_keeps_period() {
+        let mut system = RatingSystem::new(TUNING);
+        let mut broken = Rating::new(&TUNING);
+        broken.phi = f64::NAN;
+        system.insert("bob", broken);
+        system.add_game("alice", "bob", Outcome::Win);
+
+        assert_eq!(
+            system.try_close_period(),
+            Err(GlickoError::NonFinite("phi"))
+        );
+        assert_eq!(system.period().len(), 1);
+        assert_eq!(system.get(&"alice").unwrap().mu, MU);
+    }
+
+    #[test]
+    fn invalid_games() {
+        let mut system = RatingSystem::new(TUNING);
+        assert_eq!(
+            system.try_add_game("alice", "alice", Outcome::Win),
+            Err(GlickoError::DuplicatePlayer)
+        );
+        assert_eq!(
+            system.try_add_game("alice", "bob", Outcome::Score(2.0)),
+            Err(GlickoError::InvalidOutcome(2.0))
+        );
+        assert_eq!(
+            system.try_add_weighted_game("alice", "bob", Outcome::Win, 0.0),
+            Err(GlickoError::InvalidWeight(0.0))
+        );
+        assert!(system.is_empty());
+        assert!(system.period().is_empty());
+
+        // Refused games never block the period from closing
+        system.add_game("alice", "bob", Outcome::Win);
+        assert!(system.try_close_period().is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_self_play() {
+        let mut system = RatingSystem::new(TUNING);
+        system.add_game("alice", "alice", Outcome::Win);
+    }
+}
+
+#[cfg(test)]
+mod replay_tests {
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::Outcome,
+        replay::{self, Calendar, PeriodPolicy, Replay, TimedGame},
+        system::RatingSystem,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn log() -> Vec<TimedGame<&'static str>> {
+        vec![
+            TimedGame::new(0, "alice", "bob", Outcome::Win),
+            TimedGame::new(10, "bob", "carol", Outcome::Draw),
+            TimedGame::new(100, "carol", "alice", Outcome::Win),
+            TimedGame::new(350, "alice", "bob", Outcome::Loss),
+        ]
+    }
+
+    #[test]
+    fn matches_manual_periods() {
+        let policy = PeriodPolicy::Fixed {
+            origin: 0,
+            length: 100,
+        };
+        let replayed = replay::replay(TUNING, log(), policy).unwrap();
+
+        // Periods 0, 1 and 3, with period 2 empty
+        let mut system = RatingSystem::new(TUNING);
+        system.add_game("alice", "bob", Outcome::Win);
+        system.add_game("bob", "carol", Outcome::Draw);
+        system.close_period();
+        system.add_game("carol", "alice", Outcome::Win);
+        system.close_period();
+        system.close_period();
+        system.add_game("alice", "bob", Outcome::Loss);
+        system.close_period();
+
+        for (id, rating) in &system {
+            let replayed = replayed.get(id).unwrap();
+            assert_eq!(replayed.mu, rating.mu);
+            assert_eq!(replayed.phi, rating.phi);
+            assert_eq!(replayed.sigma, rating.sigma);
+        }
+    }
+
+    #[test]
+    fn long_gap() {
+        let policy = PeriodPolicy::Fixed {
+            origin: 0,
+            length: 1,
+        };
+        let mut replay = Replay::new(TUNING, policy).with_history();
+        replay.push(TimedGame::new(0, "alice", "bob", Outcome::Win));
+        replay.push(TimedGame::new(2_000_000, "alice", "bob", Outcome::Loss));
+        let history = replay.finish_history();
+
+        // The first period, every empty period at once, then the last period
+        let snapshots = history.snapshots(&"alice");
+        assert_eq!(snapshots.len(), 3);
+        assert_eq!(snapshots[1].timestamp, 2_000_000);
+        assert_eq!(history.periods()[1].span, 1_999_999.0);
+        assert!(snapshots[1].rating.phi <= PHI);
+    }
+
+    #[test]
+    fn deterministic() {
+        for policy in [
+            PeriodPolicy::Games(2),
+            PeriodPolicy::Calendar(Calendar::Day),
+        ] {
+            let first = replay::replay(TUNING, log(), policy).unwrap();
+            let second = replay::replay(TUNING, log(), policy).unwrap();
+            for (id, rating) in &first {
+                let other = second.get(id).unwrap();
+                assert_eq!(rating.mu.to_bits(), other.mu.to_bits());
+                assert_eq!(rating.phi.to_bits(), other.phi.to_bits());
+                assert_eq!(rating.sigma.to_bits(), other.sigma.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn platform_independent() {
+        // Pinned on one platform, so any other platform's math library would show up here
+        let system = replay::replay(TUNING, log(), PeriodPolicy::Games(2)).unwrap();
+        let alice = system.get(&"alice").unwrap();
+        assert_eq!(
+            (
+                alice.mu.to_bits(),
+                alice.phi.to_bits(),
+                alice.sigma.to_bits()
+            ),
+            (0x4094d8376ecee26c, 0x406c76d11b60cc3f, 0x3f78937bfe3f5760)
+        );
+    }
+
+    #[test]
+    fn calendar_boundaries() {
+        // 2023-12-31 23:59:59 and 2024-01-01 00:00:00 UTC, a Sunday and a Monday
+        let sunday = 1_704_067_199;
+        let monday = 1_704_067_200;
+        for calendar in [
+            Calendar::Day,
+            Calendar::Week,
+            Calendar::Month,
+            Calendar::Year,
+        ] {
+            let policy = PeriodPolicy::Calendar(calendar);
+            assert_eq!(policy.period(monday, 0) - policy.period(sunday, 0), 1);
+        }
+        let month = PeriodPolicy::Calendar(Calendar::Month);
+        // 2024-02-29 and 2024-03-01
+        assert_eq!(month.period(1_709_164_800, 0), 2024 * 12 + 1);
+        assert_eq!(month.period(1_709_251_200, 0), 2024 * 12 + 2);
+        // 1969-12-31
+        assert_eq!(month.period(-1, 0), 1969 * 12 + 11);
+        let week = PeriodPolicy::Calendar(Calendar::Week);
+        assert_eq!(week.period(monday, 0), week.period(monday + 6 * 86_400, 0));
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mut replay = Replay::new(TUNING, PeriodPolicy::Games(10));
+        replay.push(TimedGame::new(100, 1, 2, Outcome::Win));
+        assert_eq!(
+            replay.try_push(TimedGame::new(50, 2, 1, Outcome::Win)),
+            Err(GlickoError::OutOfOrder)
+        );
+        assert_eq!(replay.system().period().len(), 1);
+    }
+
+    #[test]
+    fn unratable_games() {
+        let policy = PeriodPolicy::Fixed {
+            origin: 0,
+            length: 100,
+        };
+        let mut replay = Replay::new(TUNING, policy);
+        replay.push(TimedGame::new(0, "a", "b", Outcome::Win));
+        assert_eq!(
+            replay.try_push(TimedGame::new(10, "a", "a", Outcome::Win)),
+            Err(GlickoError::DuplicatePlayer)
+        );
+        assert_eq!(
+            replay.try_push(TimedGame::new(150, "a", "b", Outcome::Score(2.0))),
+            Err(GlickoError::InvalidOutcome(2.0))
+        );
+        assert_eq!(replay.system().period().len(), 1);
+
+        // Refused games leave the replay able to continue
+        replay.push(TimedGame::new(150, "b", "a", Outcome::Win));
+        let system = replay.finish();
+        assert!(system.get(&"a").unwrap().phi < PHI);
+        assert!(system.get(&"b").unwrap().mu > MU);
+    }
+
+    #[test]
+    fn invalid_policy() {
+        let mut replay = Replay::new(TUNING, PeriodPolicy::Games(0));
+        assert_eq!(
+            replay.try_push(TimedGame::new(0, 1, 2, Outcome::Win)),
+            Err(GlickoError::NonPositive("policy.games"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod history_tests {
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::Outcome,
+        history::RatingHistory,
+        replay::{Calendar, PeriodPolicy, Replay, TimedGame},
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn history() -> RatingHistory<&'static str> {
+        let mut history = RatingHistory::new(TUNING);
+        history.add_game("alice", "bob", Outcome::Win);
+        history.close_period_at(100);
+        history.add_game("alice", "bob", Outcome::Win);
+        history.close_period_at(200);
+        history.close_period_at(300);
+        history
+    }
+
+    #[test]
+    fn rating_at() {
+        let history = history();
+        assert!(history.rating_at(&"alice", 99).is_none());
+        let first = history.rating_at(&"alice", 100).unwrap();
+        let second = history.rating_at(&"alice", 299).unwrap();
+        assert!(first.mu > MU);
+        assert!(second.mu > first.mu);
+        // Only decayed during the last period
+        let last = history.rating_at(&"alice", 1_000).unwrap();
+        assert_eq!(last.mu, second.mu);
+        assert!(last.phi > second.phi);
+        assert_eq!(last.mu, history.system().get(&"alice").unwrap().mu);
+        assert!(history.rating_at(&"carol", 1_000).is_none());
+    }
+
+    #[test]
+    fn trajectory() {
+        let history = history();
+        assert_eq!(history.trajectory(&"bob", 100, 200).len(), 2);
+        assert_eq!(history.trajectory(&"bob", 101, 299).len(), 1);
+        assert_eq!(history.trajectory(&"bob", 0, 1_000).len(), 3);
+        assert!(history.trajectory(&"bob", 301, 400).is_empty());
+        assert!(history.trajectory(&"bob", 200, 100).is_empty());
+    }
+
+    #[test]
+    fn retention() {
+        let mut history = history();
+        let before = *history.rating_at(&"alice", 250).unwrap();
+        history.retain_since(250);
+        assert_eq!(history.snapshots(&"alice").len(), 2);
+        assert_eq!(history.rating_at(&"alice", 250).unwrap().mu, before.mu);
+        assert!(history.rating_at(&"alice", 150).is_none());
+    }
+
+    #[test]
+    fn close_out_of_order() {
+        let mut history = history();
+        history.add_game("alice", "bob", Outcome::Draw);
+        assert_eq!(
+            history.try_close_period_at(250),
+            Err(GlickoError::OutOfOrder)
+        );
+        assert_eq!(history.system().period().len(), 1);
+        assert_eq!(history.snapshots(&"alice").len(), 3);
+    }
+
+    #[test]
+    fn close_out_of_order_after_retention() {
+        let mut history = RatingHistory::new(TUNING);
+        history.add_game("a", "b", Outcome::Win);
+        history.close_period_at(100);
+        history.close_period_at(200);
+        history.retain_since(1000);
+        assert!(history.periods().is_empty());
+
+        assert_eq!(
+            history.try_close_period_at(50),
+            Err(GlickoError::OutOfOrder)
+        );
+        assert_eq!(
+            history.try_decay_periods_at(50, 1.0),
+            Err(GlickoError::OutOfOrder)
+        );
+        assert!(history.rating_at(&"a", 150).is_none());
+        assert_eq!(history.snapshots(&"a").len(), 1);
+    }
+
+    #[test]
+    fn replay_period_ends() {
+        // 2024-01-15 and 2024-03-10 UTC, with an empty February between them
+        let mut replay =
+            Replay::new(TUNING, PeriodPolicy::Calendar(Calendar::Month)).with_history();
+        replay.push(TimedGame::new(1_705_276_800, "alice", "bob", Outcome::Win));
+        replay.push(TimedGame::new(1_710_028_800, "alice", "bob", Outcome::Win));
+        let history = replay.finish_history();
+
+        let ends: Vec<_> = history
+            .snapshots(&"alice")
+            .iter()
+            .map(|snapshot| snapshot.timestamp)
+            .collect();
+        // 2024-02-01, 2024-03-01 and 2024-04-01
+        assert_eq!(ends, vec![1_706_745_600, 1_709_251_200, 1_711_929_600]);
+    }
+
+    #[test]
+    fn replay_game_count_ends() {
+        let mut replay = Replay::new(TUNING, PeriodPolicy::Games(2)).with_history();
+        replay.push(TimedGame::new(5, 1, 2, Outcome::Win));
+        replay.push(TimedGame::new(7, 1, 2, Outcome::Win));
+        replay.push(TimedGame::new(9, 1, 2, Outcome::Win));
+        let history = replay.finish_history();
+
+        let ends: Vec<_> = history
+            .snapshots(&1)
+            .iter()
+            .map(|snapshot| snapshot.timestamp)
+            .collect();
+        assert_eq!(ends, vec![7, 9]);
+    }
+
+    #[test]
+    fn replay_without_history() {
+        let mut replay = Replay::new(TUNING, PeriodPolicy::Games(1));
+        replay.push(TimedGame::new(0, 1, 2, Outcome::Win));
+        let history = replay.finish_history();
+        assert!(history.snapshots(&1).is_empty());
+        assert!(history.system().get(&1).unwrap().mu > MU);
+    }
+}
+
+#[cfg(test)]
+mod rollback_tests {
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        error::GlickoError,
+        game::Outcome,
+        history::RatingHistory,
+        replay::{PeriodPolicy, Replay, TimedGame},
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    fn log() -> Vec<TimedGame<&'static str>> {
+        vec![
+            TimedGame::new(0, "alice", "bob", Outcome::Win),
+            TimedGame::new(10, "carol", "dave", Outcome::Draw),
+            TimedGame::new(20, "bob", "carol", Outcome::Loss),
+            TimedGame::new(30, "dave", "alice", Outcome::Win),
+            TimedGame::new(150, "bob", "erin", Outcome::Win),
+            TimedGame::new(160, "dave", "frank", Outcome::Win),
+            TimedGame::new(170, "frank", "erin", Outcome::Draw),
+            TimedGame::new(420, "erin", "frank", Outcome::Loss),
+            TimedGame::new(430, "alice", "grace", Outcome::Draw),
+        ]
+    }
+
+    fn history(log: Vec<TimedGame<&'static str>>) -> RatingHistory<&'static str> {
+        let policy = PeriodPolicy::Fixed {
+            origin: 0,
+            length: 100,
+        };
+        let mut replay = Replay::new(TUNING, policy).with_history();
+        for game in log {
+            replay.push(game);
+        }
+        replay.finish_history()
+    }
+
+    fn assert_same(left: &RatingHistory<&'static str>, right: &RatingHistory<&'static str>) {
+        for (id, rating) in left.system() {
+            let other = right.system().get(id).unwrap();
+            assert_eq!(rating.mu.to_bits(), other.mu.to_bits(), "{id}");
+            assert_eq!(rating.phi.to_bits(), other.phi.to_bits(), "{id}");
+            assert_eq!(rating.sigma.to_bits(), other.sigma.to_bits(), "{id}");
+            for (snapshot, other) in left.snapshots(id).iter().zip(right.snapshots(id)) {
+                assert_eq!(snapshot.timestamp, other.timestamp);
+                assert_eq!(snapshot.rating.mu.to_bits(), other.rating.mu.to_bits());
+                assert_eq!(snapshot.rating.phi.to_bits(), other.rating.phi.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_full_replay() {
+        let mut history = history(log());
+        let recomputed = history.invalidate(0, 1);
+
+        let mut corrected = log();
+        corrected.remove(1);
+        assert_same(&history, &self::history(corrected));
+        assert_eq!(history.periods()[0].games.len(), 3);
+
+        // Carol and Dave, then Frank who played Dave, then Erin who played Frank
+        let mut recomputed: Vec<_> = recomputed.into_iter().collect();
+        recomputed.sort();
+        assert_eq!(recomputed, vec!["carol", "dave", "erin", "frank"]);
+    }
+
+    #[test]
+    fn first_period() {
+        let mut history = history(log());
+        let before = history.snapshots(&"grace").to_vec();
+        let recomputed = history.invalidate(0, 2);
+
+        let mut corrected = log();
+        corrected.remove(2);
+        assert_same(&history, &self::history(corrected));
+        assert!(!recomputed.contains(&"grace"));
+        assert_eq!(
+            history.snapshots(&"grace")[0].rating.mu.to_bits(),
+            before[0].rating.mu.to_bits()
+        );
+    }
+
+    #[test]
+    fn after_retention() {
+        let mut history = history(log());
+        history.retain_since(150);
+        // Periods 2 and 3 are empty, so they were decayed as one
+        assert_eq!(history.periods().len(), 3);
+        assert_eq!(history.periods()[1].span, 2.0);
+        history.invalidate(0, 1);
+
+        let mut corrected = log();
+        corrected.remove(5);
+        let full = self::history(corrected);
+        for (id, rating) in history.system() {
+            assert_eq!(
+                rating.mu.to_bits(),
+                full.system().get(id).unwrap().mu.to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_game() {
+        let mut history = history(log());
+        assert_eq!(history.try_invalidate(1, 5), Err(GlickoError::UnknownGame));
+        assert_eq!(history.try_invalidate(9, 0), Err(GlickoError::UnknownGame));
+        assert_eq!(history.periods()[1].games.len(), 3);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::glicko2::{
+        constants::{MU, PHI, SIGMA, TAU},
+        game::Outcome,
+        rating::Rating,
+        tuning::Tuning,
+    };
+
+    const TUNING: Tuning = Tuning::new(MU, PHI, SIGMA, TAU);
+
+    #[test]
+    fn rating_round_trip() {
+        let rating = Rating {
+            mu: 1450.0,
+            phi: 200.0,
+            sigma: 0.0059,
+            tuning: TUNING,
+        };
+        let json = serde_json::to_string(&rating).unwrap();
+        let restored: Rating = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.mu, rating.mu);
+        assert_eq!(restored.phi, rating.phi);
+        assert_eq!(restored.sigma, rating.sigma);
+        assert_eq!(restored.tuning(), &TUNING);
+    }
+
+    #[test]
+    fn rating_layout() {
+        let rating = Rating::new(&TUNING);
+        let value = serde_json::to_value(rating).unwrap();
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["mu"], 1500.0);
+        assert_eq!(value["phi"], 350.0);
+        assert_eq!(value["sigma"], 0.006);
+        assert_eq!(value["tuning"]["tau"], 1.3);
+        assert!(value.get("is_scaled").is_none());
+    }
+
+    #[test]
+    fn missing_tuning_fields_use_defaults() {
+        let json = r#"{"version":1,"mu":1600.0,"phi":80.0,"sigma":0.05,"tuning":{"mu":1500.0,"phi":350.0,"sigma":0.06,"tau":0.5}}"#;
+        let rating: Rating = serde_json::from_str(json).unwrap();
+        assert_eq!(rating.mu, 1600.0);
+        assert_eq!(rating.tuning().tau, 0.5);
+        assert_eq!(rating.tuning().solver, Tuning::default().solver);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let json = r#"{"version":99,"mu":1600.0,"phi":80.0,"sigma":0.05,"tuning":{}}"#;
+        let error = serde_json::from_str::<Rating>(json).unwrap_err();
+        assert!(error.to_string().contains("version 99"));
+    }
+
+    #[test]
+    fn invalid_rating() {
+        let json = r#"{"version":1,"mu":1600.0,"phi":-80.0,"sigma":0.05,"tuning":{}}"#;
+        assert!(serde_json::from_str::<Rating>(json).is_err());
+    }
+
+    #[test]
+    fn tuning_and_outcome_round_trip() {
+        let tuning: Tuning =
+            serde_json::from_str(&serde_json::to_string(&TUNING).unwrap()).unwrap();
+        assert_eq!(tuning, TUNING);
+
+        for outcome in [
+            Outcome::Win,
+            Outcome::Draw,
+            Outcome::Loss,
+            Outcome::Score(0.625),
+        ] {
+            let json = serde_json::to_string(&outcome).unwrap();
+            assert_eq!(serde_json::from_str::<Outcome>(&json).unwrap(), outcome);
+        }
+    }
+}